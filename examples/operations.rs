@@ -10,8 +10,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Schritt 1: Zahlung erstellen
     let create = client
         .create_payment_operation(
-            CreatePaymentPayload::new(10.0, client.customer_code.clone(), "Оплата услуг")
-                .payment_modes([PaymentMode::Card, PaymentMode::Sbp]),
+            CreatePaymentPayload::new(
+                "10.00".parse()?,
+                client.customer_code.clone(),
+                "Оплата услуг",
+            )
+            .payment_modes([PaymentMode::Card, PaymentMode::Sbp]),
             PaymentPath::Standard,
         )
         .await?;
@@ -1,4 +1,4 @@
-use tochka_sdk::{Client, RefundPayload};
+use tochka_sdk::{Client, Money, RefundPayload};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -13,8 +13,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let refund_amount = std::env::var("REFUND_AMOUNT")
         .ok()
-        .and_then(|v| v.parse::<f64>().ok())
-        .unwrap_or(1.0);
+        .and_then(|v| v.parse::<Money>().ok())
+        .unwrap_or_else(|| "1.00".parse().unwrap());
 
     let refund = client
         .refund_payment_operation(
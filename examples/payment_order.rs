@@ -0,0 +1,32 @@
+use chrono::Utc;
+use tochka_sdk::{Client, PaymentOrderPayload};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+    let client = Client::new().await?;
+
+    let account_code = std::env::var("ACCOUNT_CODE").expect("Set ACCOUNT_CODE (20 digits)");
+    let bank_code = std::env::var("BANK_CODE").unwrap_or_else(|_| "044525104".into());
+
+    let payload = PaymentOrderPayload::new(
+        account_code,
+        bank_code,
+        "044525593",
+        "40702810100000000001",
+        "ООО Ромашка",
+        "1.00".parse()?,
+        Utc::now().date_naive(),
+        "Тестовый платёж. Без НДС",
+    )
+    .counterparty_inn("7701234567")
+    .counterparty_kpp("770101001");
+
+    let created = client.create_payment(payload).await?;
+    println!("Payment order created:\n{:#?}", created.data);
+
+    let status = client.get_payment_status(&created.data.request_id).await?;
+    println!("Payment order status:\n{:#?}", status.data);
+
+    Ok(())
+}
@@ -0,0 +1,343 @@
+use crate::{ApiError, Error, client::dispatch};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::{fmt, sync::Arc};
+use tokio::sync::Mutex;
+
+/// RU: Адрес выдачи токенов OAuth2 Точки. EN: Tochka OAuth2 token endpoint.
+pub const TOKEN_URL: &str = "https://enter.tochka.com/connect/token";
+
+/// RU: Адрес страницы авторизации OAuth2 Точки. EN: Tochka OAuth2 authorization endpoint.
+pub const AUTHORIZE_URL: &str = "https://enter.tochka.com/connect/authorize";
+
+/// RU: За сколько секунд до истечения токен считается просроченным.
+/// EN: How long before expiry an access token is refreshed proactively.
+const EXPIRY_LEEWAY_SECS: i64 = 60;
+
+/// RU: Источник токена доступа для [`Client`](crate::Client).
+/// EN: Source of access tokens used by [`Client`](crate::Client).
+///
+/// `access_token` вызывается перед каждым запросом, `refresh` — один раз после `401`.
+#[async_trait]
+pub trait TokenProvider: fmt::Debug + Send + Sync {
+    /// RU: Актуальный токен доступа. EN: Current access token.
+    async fn access_token(&self) -> Result<String, Error>;
+
+    /// RU: Обновить токен, отклонённый сервером (`stale`). `None` — обновление не поддерживается.
+    /// EN: Refresh after the server rejected `stale`. `None` means the provider cannot refresh.
+    ///
+    /// Если токен уже сменился (его обновил параллельный запрос), провайдер должен вернуть
+    /// текущий токен, не обращаясь к token endpoint.
+    async fn refresh(&self, _stale: &str) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+}
+
+/// RU: Статический токен без обновления. EN: Static bearer token without refresh.
+#[derive(Clone)]
+pub struct StaticToken(pub String);
+
+impl fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StaticToken(..)")
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn access_token(&self) -> Result<String, Error> {
+        Ok(self.0.clone())
+    }
+}
+
+/// RU: Пара токенов OAuth2. EN: OAuth2 token pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenPair {
+    /// RU: Токен доступа. EN: Access token.
+    pub access_token: String,
+    /// RU: Токен обновления. EN: Refresh token.
+    pub refresh_token: Option<String>,
+    /// RU: Момент истечения токена доступа. EN: Access token expiry.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl TokenPair {
+    /// RU: Истекает ли токен в ближайшее время. EN: Whether the token is (about to be) expired.
+    pub fn is_expiring(&self) -> bool {
+        self.expires_at - Duration::seconds(EXPIRY_LEEWAY_SECS) <= Utc::now()
+    }
+}
+
+/// RU: Ответ token endpoint. EN: Raw token endpoint response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: i64,
+}
+
+impl From<TokenResponse> for TokenPair {
+    fn from(resp: TokenResponse) -> Self {
+        Self {
+            access_token: resp.access_token,
+            refresh_token: resp.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(resp.expires_in),
+        }
+    }
+}
+
+/// RU: Настройки OAuth2-приложения. EN: OAuth2 application settings.
+#[derive(Clone)]
+pub struct OAuthConfig {
+    /// RU: Идентификатор приложения. EN: Application client ID.
+    pub client_id: String,
+    /// RU: Секрет приложения. EN: Application client secret.
+    pub client_secret: String,
+    /// RU: Адрес возврата после авторизации. EN: Redirect URI.
+    pub redirect_uri: String,
+    /// RU: Адрес выдачи токенов. EN: Token endpoint.
+    pub token_url: String,
+    /// RU: Адрес страницы авторизации. EN: Authorization endpoint.
+    pub authorize_url: String,
+}
+
+impl fmt::Debug for OAuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthConfig")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("token_url", &self.token_url)
+            .field("authorize_url", &self.authorize_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuthConfig {
+    /// RU: Настройки с адресами Точки по умолчанию. EN: Settings with default Tochka endpoints.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            token_url: TOKEN_URL.into(),
+            authorize_url: AUTHORIZE_URL.into(),
+        }
+    }
+
+    /// RU: Переопределить token endpoint. EN: Override the token endpoint.
+    pub fn token_url(mut self, url: impl Into<String>) -> Self {
+        self.token_url = url.into();
+        self
+    }
+
+    /// RU: Ссылка, по которой пользователь подписывает разрешение.
+    /// EN: URL where the user authorizes the consent.
+    ///
+    /// Если `authorize_url` не разбирается как URL, вернётся [`Error::Config`].
+    pub fn authorization_url(
+        &self,
+        consent_id: &str,
+        state: &str,
+        scope: &str,
+    ) -> Result<reqwest::Url, Error> {
+        reqwest::Url::parse_with_params(
+            &self.authorize_url,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("response_type", "code"),
+                ("state", state),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("scope", scope),
+                ("consent_id", consent_id),
+            ],
+        )
+        .map_err(|e| Error::Config(format!("invalid authorize URL {}: {e}", self.authorize_url)))
+    }
+
+    /// RU: Получить токен приложения (`client_credentials`), нужен для создания разрешений.
+    /// EN: Obtain an application token via `client_credentials`.
+    pub async fn client_credentials(
+        &self,
+        http: &reqwest::Client,
+        scope: &str,
+    ) -> Result<TokenPair, Error> {
+        debug!("Requesting client_credentials token");
+        self.request_token(
+            http,
+            &[
+                ("grant_type", "client_credentials"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("scope", scope),
+            ],
+        )
+        .await
+    }
+
+    /// RU: Обменять код авторизации на пару токенов. EN: Exchange an authorization code.
+    pub async fn exchange_code(
+        &self,
+        http: &reqwest::Client,
+        code: &str,
+    ) -> Result<TokenPair, Error> {
+        debug!("Exchanging authorization code for tokens");
+        self.request_token(
+            http,
+            &[
+                ("grant_type", "authorization_code"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("code", code),
+            ],
+        )
+        .await
+    }
+
+    /// RU: Обновить токен доступа по refresh token. EN: Refresh the access token.
+    pub async fn refresh_token(
+        &self,
+        http: &reqwest::Client,
+        refresh_token: &str,
+    ) -> Result<TokenPair, Error> {
+        debug!("Refreshing access token");
+        self.request_token(
+            http,
+            &[
+                ("grant_type", "refresh_token"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("refresh_token", refresh_token),
+            ],
+        )
+        .await
+    }
+
+    async fn request_token(
+        &self,
+        http: &reqwest::Client,
+        form: &[(&str, &str)],
+    ) -> Result<TokenPair, Error> {
+        let resp = dispatch(http.post(&self.token_url).form(form)).await?;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.text().await.unwrap_or_default();
+        if !status.is_success() {
+            debug!("Token endpoint responded with {status}");
+            return Err(Error::Api(Box::new(ApiError::from_response(
+                status, &headers, body,
+            ))));
+        }
+
+        serde_json::from_str::<TokenResponse>(&body)
+            .map(TokenPair::from)
+            .map_err(|e| Error::Deserialize {
+                message: e.to_string(),
+                path: String::new(),
+                raw: body,
+            })
+    }
+}
+
+/// RU: Колбэк, получающий новую пару токенов (например, чтобы сохранить её в БД).
+/// EN: Callback receiving every refreshed token pair (e.g. to persist it).
+pub type TokenRefreshHook = Arc<dyn Fn(&TokenPair) + Send + Sync>;
+
+/// RU: Провайдер токенов OAuth2 с автоматическим обновлением.
+/// EN: OAuth2 token provider with automatic renewal.
+pub struct OAuthTokenProvider {
+    config: OAuthConfig,
+    http: reqwest::Client,
+    tokens: Mutex<TokenPair>,
+    on_refresh: Option<TokenRefreshHook>,
+}
+
+impl fmt::Debug for OAuthTokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthTokenProvider")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuthTokenProvider {
+    /// RU: Создать провайдер из сохранённой пары токенов. EN: Create from a stored token pair.
+    pub fn new(config: OAuthConfig, tokens: TokenPair) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+            tokens: Mutex::new(tokens),
+            on_refresh: None,
+        }
+    }
+
+    /// RU: HTTP-клиент для token endpoint. EN: HTTP client for the token endpoint.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// RU: Колбэк на каждое обновление токенов. EN: Hook invoked after each refresh.
+    pub fn on_refresh(mut self, hook: impl Fn(&TokenPair) + Send + Sync + 'static) -> Self {
+        self.on_refresh = Some(Arc::new(hook));
+        self
+    }
+
+    /// RU: Текущая пара токенов. EN: Current token pair.
+    pub async fn tokens(&self) -> TokenPair {
+        self.tokens.lock().await.clone()
+    }
+
+    async fn refresh_locked(&self, tokens: &mut TokenPair) -> Result<Option<String>, Error> {
+        let Some(refresh_token) = tokens.refresh_token.clone() else {
+            debug!("No refresh token available");
+            return Ok(None);
+        };
+        let mut fresh = self
+            .config
+            .refresh_token(&self.http, &refresh_token)
+            .await?;
+        // Точка может не вернуть новый refresh token — сохраняем старый.
+        if fresh.refresh_token.is_none() {
+            fresh.refresh_token = Some(refresh_token);
+        }
+        if let Some(hook) = &self.on_refresh {
+            hook(&fresh);
+        }
+        *tokens = fresh;
+
+        Ok(Some(tokens.access_token.clone()))
+    }
+}
+
+#[async_trait]
+impl TokenProvider for OAuthTokenProvider {
+    async fn access_token(&self) -> Result<String, Error> {
+        let mut tokens = self.tokens.lock().await;
+        if tokens.is_expiring() {
+            debug!("Access token is expiring, refreshing before request");
+            if let Some(token) = self.refresh_locked(&mut tokens).await? {
+                return Ok(token);
+            }
+        }
+
+        Ok(tokens.access_token.clone())
+    }
+
+    async fn refresh(&self, stale: &str) -> Result<Option<String>, Error> {
+        let mut tokens = self.tokens.lock().await;
+        // Пока ждали блокировку, токен мог обновить другой запрос, получивший тот же 401.
+        // Повторное обновление потратило бы уже использованный refresh token.
+        if tokens.access_token != stale {
+            debug!("Access token was already refreshed by a concurrent request");
+            return Ok(Some(tokens.access_token.clone()));
+        }
+        self.refresh_locked(&mut tokens).await
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{ApiVersion, Error, Jwk, Service, jwt::fetch_jwk};
-use log::debug;
-use std::{any::type_name, time::Duration};
+use crate::{
+    ApiError, ApiVersion, Error, HttpJwkSource, IDEMPOTENCY_KEY_HEADER, IdempotencyKey,
+    IdempotencyStore, Jwk, JwkCache, JwkSource, RateLimiter, RetryPolicy, Service, StaticJwkSource,
+    StaticToken, StoredResponse, TokenProvider, idempotency::request_fingerprint,
+    retry::retry_after,
+};
+use log::{debug, warn};
+use std::{any::type_name, sync::Arc, time::Duration};
+use validator::Validate;
 
 /// RU: Базовый URL продакшн-окружения Tochka API.  
 /// EN: Base Tochka API production URL without version suffix.
@@ -48,6 +54,14 @@ impl Environment {
     }
 }
 
+/// RU: Таймаут запроса по умолчанию.  
+/// EN: Default request timeout.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// RU: Таймаут установки соединения по умолчанию.  
+/// EN: Default connect timeout.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// RU: Основной клиент SDK Tochka.  
 /// EN: Main Tochka SDK client.
 #[derive(Clone, Debug)]
@@ -60,57 +74,43 @@ pub struct Client {
     pub customer_code: Option<String>,
     /// RU: Текущая среда (песочница или прод). EN: Current environment.
     env: Environment,
-    /// Токен для расшифровки запросов вебхукам
-    pub(crate) jwk: Jwk,
-    /// RU: JWT/оAuth токен доступа. EN: Access token (JWT/OAuth).
-    token: String,
+    /// RU: Базовый URL (с учётом переопределения). EN: Base URL (possibly overridden).
+    base_url: String,
+    /// RU: Кэш ключей для проверки вебхуков. EN: Webhook verification key cache.
+    pub(crate) jwks: Arc<JwkCache>,
+    /// RU: Источник токена доступа (статический или OAuth2). EN: Access token source.
+    auth: Arc<dyn TokenProvider>,
+    /// RU: Политика повторов. EN: Retry policy.
+    retry: RetryPolicy,
+    /// RU: Общий для клонов ограничитель частоты. EN: Rate limiter shared across clones.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// RU: Хранилище ответов по ключам идемпотентности. EN: Idempotent response store.
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    /// RU: Не проверять payload перед отправкой. EN: Send payloads without validation.
+    skip_validation: bool,
 }
 
 impl Client {
     /// Создать клиента для указанного окружения.  
+    ///
+    /// Читает `TOCHKA_ENV` и `TOCHKA_TOKEN` из окружения и делегирует в [`ClientBuilder`].
     pub async fn new() -> Result<Self, Error> {
-        let version = env!("CARGO_PKG_VERSION");
-        debug!("Initializing Tochka SDK client v{version}");
         let env: Environment = std::env::var("TOCHKA_ENV")
             .unwrap_or(String::from("SANDBOX"))
             .into();
-        debug!(
-            "Environment resolved as {:?}, base URL {}",
-            env,
-            env.base_url()
-        );
-        let token = match env {
-            Environment::Production => {
-                debug!("Using production token from TOCHKA_TOKEN");
-                std::env::var("TOCHKA_TOKEN")?
-            }
-            Environment::Sandbox => {
-                debug!("Using sandbox placeholder token");
-                "sandbox.jwt.token".to_string()
-            }
-        };
-
-        let jwk = fetch_jwk().await?;
-        debug!("Fetched JWK with kid {:?}", jwk.kid);
+        let mut builder = Client::builder().environment(env.clone());
+        if let Environment::Production = env {
+            debug!("Using production token from TOCHKA_TOKEN");
+            builder = builder.token(std::env::var("TOCHKA_TOKEN")?);
+        }
 
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(20))
-            .connect_timeout(Duration::from_secs(5))
-            .user_agent(format!("tochka-rust-sdk/{version}"))
-            .pool_idle_timeout(Some(Duration::from_secs(90)))
-            .pool_max_idle_per_host(20)
-            .build()
-            .map_err(|e| Error::Config(e.to_string()))?;
-        debug!("Reqwest client constructed with standard timeouts");
+        builder.build().await
+    }
 
-        Ok(Self {
-            client,
-            env,
-            token: token.into(),
-            jwk,
-            client_id: None,
-            customer_code: None,
-        })
+    /// RU: Создать билдер клиента с явными параметрами.  
+    /// EN: Start building a client from explicit settings.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
     }
 
     /// RU: Получить customer_code для Business-аккаунта.  
@@ -136,6 +136,203 @@ impl Client {
 
         Ok(self)
     }
+
+    /// RU: Кэш ключей проверки вебхуков. EN: Webhook verification key cache.
+    pub fn jwks(&self) -> &JwkCache {
+        &self.jwks
+    }
+
+    /// RU: Текущее окружение клиента. EN: Environment the client targets.
+    pub fn environment(&self) -> &Environment {
+        &self.env
+    }
+
+    /// RU: Базовый URL, к которому обращается клиент. EN: Base URL the client talks to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+/// RU: Билдер клиента с явными учётными данными и настройками.  
+/// EN: Client builder with explicit credentials and settings.
+///
+/// Ничего не читает из переменных окружения, поэтому подходит для нескольких тенантов
+/// в одном процессе и для тестов против локального мок-сервера.
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    env: Environment,
+    base_url: Option<String>,
+    auth: Option<Arc<dyn TokenProvider>>,
+    customer_code: Option<String>,
+    client_id: Option<String>,
+    http: Option<reqwest::Client>,
+    jwks: Option<JwkCache>,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    skip_validation: bool,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// RU: Окружение (песочница/прод). EN: Target environment.
+    pub fn environment(mut self, env: Environment) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// RU: Переопределить базовый URL (например, для мок-сервера).  
+    /// EN: Override the base URL (e.g. to point at a mock server).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// RU: Статический токен доступа. EN: Static access token.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Some(Arc::new(StaticToken(token.into())));
+        self
+    }
+
+    /// RU: Провайдер токенов, например [`OAuthTokenProvider`](crate::OAuthTokenProvider).
+    /// EN: Token provider, e.g. [`OAuthTokenProvider`](crate::OAuthTokenProvider).
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.auth = Some(Arc::new(provider));
+        self
+    }
+
+    /// RU: Код клиента для эквайринга. EN: Customer code used by acquiring APIs.
+    pub fn customer_code(mut self, customer_code: impl Into<String>) -> Self {
+        self.customer_code = Some(customer_code.into());
+        self
+    }
+
+    /// RU: Идентификатор приложения (нужен для вебхуков). EN: Application client ID (webhooks).
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// RU: Готовый HTTP-клиент reqwest. Таймауты билдера в этом случае игнорируются.  
+    /// EN: Preconfigured reqwest client. Builder timeouts are ignored when set.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// RU: Заранее известный JWK — ключ не будет запрашиваться по сети.  
+    /// EN: Pre-supplied JWK, skips fetching the key over the network.
+    pub fn jwk(mut self, jwk: Jwk) -> Self {
+        self.jwks = Some(JwkCache::new(StaticJwkSource(jwk.into())));
+        self
+    }
+
+    /// RU: Свой источник ключей вебхуков. EN: Custom webhook key source.
+    pub fn jwk_source(mut self, source: impl JwkSource + 'static) -> Self {
+        self.jwks = Some(JwkCache::new(source));
+        self
+    }
+
+    /// RU: Настроенный кэш ключей (TTL, интервал обновления). EN: Preconfigured key cache.
+    pub fn jwk_cache(mut self, cache: JwkCache) -> Self {
+        self.jwks = Some(cache);
+        self
+    }
+
+    /// RU: Политика повторов; по умолчанию [`RetryPolicy::default`].
+    /// EN: Retry policy; defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// RU: Ограничитель частоты; `Arc` позволяет разделить квоту между клиентами.
+    /// EN: Rate limiter; pass an `Arc` to share the quota between clients.
+    pub fn rate_limiter(mut self, limiter: impl Into<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = Some(limiter.into());
+        self
+    }
+
+    /// RU: Хранилище ответов для запросов с [`IdempotencyKey`]; одно `Arc` можно отдать
+    /// нескольким клиентам. EN: Response store for requests sent with an [`IdempotencyKey`].
+    pub fn idempotency_store(mut self, store: Arc<dyn IdempotencyStore>) -> Self {
+        self.idempotency_store = Some(store);
+        self
+    }
+
+    /// RU: Отправлять payload без проверки `validator` — как есть, на усмотрение API.
+    /// EN: Send payloads as is, skipping `validator` checks.
+    pub fn skip_validation(mut self, skip: bool) -> Self {
+        self.skip_validation = skip;
+        self
+    }
+
+    /// RU: Таймаут запроса. EN: Request timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// RU: Таймаут соединения. EN: Connect timeout.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// RU: Собрать клиента. EN: Build the client.
+    pub async fn build(self) -> Result<Client, Error> {
+        let version = env!("CARGO_PKG_VERSION");
+        debug!("Initializing Tochka SDK client v{version}");
+        let env = self.env;
+        let base_url = self.base_url.unwrap_or_else(|| env.base_url().to_string());
+        debug!("Environment resolved as {:?}, base URL {}", env, base_url);
+
+        let auth = match (self.auth, &env) {
+            (Some(auth), _) => auth,
+            (None, Environment::Sandbox) => {
+                debug!("Using sandbox placeholder token");
+                Arc::new(StaticToken("sandbox.jwt.token".to_string()))
+            }
+            (None, Environment::Production) => {
+                return Err(Error::Config(
+                    "token is required for the production environment".into(),
+                ));
+            }
+        };
+
+        let jwks = self
+            .jwks
+            .unwrap_or_else(|| JwkCache::new(HttpJwkSource::default()));
+        jwks.refresh().await?;
+
+        let client = match self.http {
+            Some(client) => client,
+            None => reqwest::Client::builder()
+                .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
+                .connect_timeout(self.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT))
+                .user_agent(format!("tochka-rust-sdk/{version}"))
+                .pool_idle_timeout(Some(Duration::from_secs(90)))
+                .pool_max_idle_per_host(20)
+                .build()
+                .map_err(|e| Error::Config(e.to_string()))?,
+        };
+        debug!("Reqwest client ready");
+
+        Ok(Client {
+            client,
+            env,
+            base_url,
+            auth,
+            jwks: Arc::new(jwks),
+            retry: self.retry.unwrap_or_default(),
+            rate_limiter: self.rate_limiter,
+            idempotency_store: self.idempotency_store,
+            skip_validation: self.skip_validation,
+            client_id: self.client_id,
+            customer_code: self.customer_code,
+        })
+    }
 }
 
 impl Client {
@@ -143,8 +340,8 @@ impl Client {
     /// EN: Build a fully-qualified URL for the given service, version and path.
     pub fn url(&self, service: Service, version: ApiVersion, path: &str) -> String {
         format!(
-            "{}{}/{}/{}",
-            self.env.base_url(),
+            "{}/{}/{}/{}",
+            self.base_url.trim_end_matches('/'),
             service.path(),
             version.as_str(),
             path.trim_start_matches('/')
@@ -153,12 +350,92 @@ impl Client {
 }
 
 impl Client {
+    /// RU: Проверить payload перед отправкой, если проверка не отключена в билдере.
+    /// EN: Validate a payload before sending unless disabled in the builder.
+    pub fn validate_payload<P: Validate>(&self, payload: &P) -> Result<(), Error> {
+        if self.skip_validation {
+            return Ok(());
+        }
+        payload.validate().map_err(|errors| {
+            debug!("Payload failed validation: {errors}");
+            Error::Validation(errors)
+        })
+    }
+
     /// RU: Отправить запрос: добавить авторизацию, проверить HTTP-статусы и десериализовать тело.  
     /// EN: Send a request with auth, map HTTP errors, and deserialize the body.
     pub async fn send<T>(&self, req: reqwest::RequestBuilder) -> Result<T, Error>
     where
         T: serde::de::DeserializeOwned,
     {
+        let body = String::from_utf8_lossy(&self.send_raw(req).await?).into_owned();
+        debug!("Raw response body: {body}");
+        deserialize_body(body)
+    }
+
+    /// RU: Как [`Client::send`], но вместе с ответом возвращает исходное тело.
+    /// EN: Like [`Client::send`], but also returns the raw body next to the parsed response.
+    pub(crate) async fn send_with_raw<T>(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<(T, String), Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let body = String::from_utf8_lossy(&self.send_raw(req).await?).into_owned();
+        debug!("Raw response body: {body}");
+        let parsed = deserialize_body(body.clone())?;
+        Ok((parsed, body))
+    }
+
+    /// RU: Отправить запрос с ключом идемпотентности. Если в хранилище уже есть ответ по
+    /// этому ключу, он возвращается без запроса к API; если ключ сохранён для другого
+    /// запроса — [`Error::IdempotencyKeyReused`].
+    /// EN: Send a request with an idempotency key, replaying a stored response if present.
+    pub async fn send_idempotent<T>(
+        &self,
+        req: reqwest::RequestBuilder,
+        key: &IdempotencyKey,
+    ) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let req = req.header(IDEMPOTENCY_KEY_HEADER, key.as_str());
+        let Some(store) = &self.idempotency_store else {
+            return self.send(req).await;
+        };
+        let fingerprint = req
+            .try_clone()
+            .and_then(|builder| builder.build().ok())
+            .map(|request| request_fingerprint(&request))
+            .unwrap_or_default();
+        if let Some(stored) = store.get(key).await? {
+            if stored.fingerprint != fingerprint {
+                return Err(Error::IdempotencyKeyReused {
+                    key: key.to_string(),
+                });
+            }
+            debug!("Replaying stored response for idempotency key {key}");
+            return deserialize_body(String::from_utf8_lossy(&stored.body).into_owned());
+        }
+
+        let body = self.send_raw(req).await?;
+        let stored = StoredResponse {
+            fingerprint,
+            body: body.clone(),
+        };
+        if let Err(err) = store.put(key, stored).await {
+            // Запрос уже выполнен: ответ важнее записи в хранилище.
+            warn!("Failed to store response for idempotency key {key}: {err}");
+        }
+        let body = String::from_utf8_lossy(&body).into_owned();
+        debug!("Raw response body: {body}");
+        deserialize_body(body)
+    }
+
+    /// RU: Отправить запрос и вернуть тело успешного ответа как есть (например, PDF).  
+    /// EN: Send a request and return the raw body of a successful response (e.g. a PDF).
+    pub async fn send_raw(&self, req: reqwest::RequestBuilder) -> Result<Vec<u8>, Error> {
         let request_snapshot = req.try_clone().and_then(|builder| builder.build().ok());
         if let Some(snapshot) = request_snapshot.as_ref() {
             debug!(
@@ -169,18 +446,7 @@ impl Client {
         } else {
             debug!("Sending request (unable to snapshot builder)");
         }
-        let resp = req.bearer_auth(&self.token).send().await.map_err(|e| {
-            if e.is_timeout() {
-                debug!("Request timed out: {e}");
-                Error::Timeout
-            } else {
-                debug!("Network error: {e}");
-                Error::Network(e.without_url().to_string())
-            }
-        })?;
-
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default(); // always capture raw JSON
+        let (status, headers, body) = self.execute(req).await?;
         if let Some(snapshot) = request_snapshot {
             debug!(
                 "Response for {} {} returned status {}",
@@ -191,59 +457,143 @@ impl Client {
         } else {
             debug!("Response received with status {}", status);
         }
-        debug!("Raw response body: {body}");
 
-        match status {
-            reqwest::StatusCode::UNAUTHORIZED => {
-                debug!("API responded with Unauthorized");
-                return Err(Error::Unauthorized);
-            }
-            reqwest::StatusCode::FORBIDDEN => {
-                debug!("API responded with Forbidden");
-                return Err(Error::Forbidden);
-            }
-            reqwest::StatusCode::NOT_FOUND => {
-                debug!("API responded with NotFound");
-                return Err(Error::NotFound);
-            }
-            reqwest::StatusCode::TOO_MANY_REQUESTS => {
-                debug!("API responded with TooManyRequests");
-                return Err(Error::TooManyRequests);
+        if !status.is_success() {
+            let body = String::from_utf8_lossy(&body).into_owned();
+            debug!("Raw response body: {body}");
+            return Err(error_for_status(status, &headers, body));
+        }
+
+        Ok(body)
+    }
+
+    /// RU: Выполнить запрос, повторяя временные ошибки по [`RetryPolicy`].
+    /// EN: Execute a request, retrying transient failures per [`RetryPolicy`].
+    async fn execute(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<(reqwest::StatusCode, reqwest::header::HeaderMap, Vec<u8>), Error> {
+        let snapshot = req.try_clone().and_then(|builder| builder.build().ok());
+        let retry_safe = snapshot.as_ref().is_some_and(RetryPolicy::is_retry_safe);
+        let service = snapshot.as_ref().and_then(|request| {
+            let url = request.url().as_str();
+            Service::from_path(url.strip_prefix(self.base_url.trim_end_matches('/'))?)
+        });
+        let mut req = req;
+        let mut attempt = 1;
+
+        loop {
+            let next = if retry_safe && attempt < self.retry.max_attempts {
+                req.try_clone()
+            } else {
+                None
+            };
+            if let (Some(limiter), Some(service)) = (&self.rate_limiter, service) {
+                limiter.acquire(service).await;
             }
-            code if code.is_server_error() => {
-                debug!("API responded with server error");
-                return Err(Error::Server(body));
+            let outcome = self.execute_once(req).await;
+            let delay = match &outcome {
+                Ok((status, headers, _)) if RetryPolicy::is_transient(*status) => {
+                    self.retry.delay(attempt, retry_after(headers))
+                }
+                Err(Error::Timeout | Error::Network(_)) => Some(self.retry.backoff(attempt)),
+                _ => None,
+            };
+            match (delay, next) {
+                (Some(delay), Some(next)) => {
+                    debug!("Attempt {attempt} failed transiently, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    req = next;
+                    attempt += 1;
+                }
+                _ => return outcome,
             }
-            _ => {}
         }
+    }
 
-        if !status.is_success() {
-            debug!("API responded with non-success status {}", status);
-            return Err(Error::Api(body));
+    /// RU: Одна попытка с токеном; после `401` обновить токен и повторить один раз.
+    /// EN: Single attempt with a bearer token; on `401` refresh once and retry.
+    async fn execute_once(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<(reqwest::StatusCode, reqwest::header::HeaderMap, Vec<u8>), Error> {
+        let retry = req.try_clone();
+        let token = self.auth.access_token().await?;
+        let mut resp = dispatch(req.bearer_auth(&token)).await?;
+
+        if resp.status() == reqwest::StatusCode::UNAUTHORIZED
+            && let Some(retry) = retry
+            && let Some(token) = self.auth.refresh(&token).await?
+        {
+            debug!("Access token refreshed after Unauthorized, retrying once");
+            resp = dispatch(retry.bearer_auth(token)).await?;
         }
 
-        // ------- Enhanced Deserialization --------
-        let mut deserializer = serde_json::Deserializer::from_str(&body);
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await.unwrap_or_default(); // always capture raw body
+        Ok((status, headers, body.to_vec()))
+    }
+}
 
-        match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
-            Ok(result) => {
-                debug!("Deserialization succeeded for {}", type_name::<T>());
-                Ok(result)
-            }
-            Err(err) => {
-                let path = err.path().to_string();
-                let inner = err.into_inner();
-                debug!(
-                    "Deserialization error for {} at {path}: {inner}",
-                    type_name::<T>()
-                );
-
-                Err(Error::Deserialize {
-                    message: inner.to_string(),
-                    path,
-                    raw: body,
-                })
-            }
+/// RU: Разобрать JSON-ответ; ошибка содержит путь в JSON и исходное тело.
+/// EN: Deserialize a JSON body, reporting the JSON path and raw body on failure.
+fn deserialize_body<T>(body: String) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    // ------- Enhanced Deserialization --------
+    let mut deserializer = serde_json::Deserializer::from_str(&body);
+
+    match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
+        Ok(result) => {
+            debug!("Deserialization succeeded for {}", type_name::<T>());
+            Ok(result)
+        }
+        Err(err) => {
+            let path = err.path().to_string();
+            let inner = err.into_inner();
+            debug!(
+                "Deserialization error for {} at {path}: {inner}",
+                type_name::<T>()
+            );
+
+            Err(Error::Deserialize {
+                message: inner.to_string(),
+                path,
+                raw: body,
+            })
         }
     }
 }
+
+/// RU: Сопоставить неуспешный HTTP-статус с ошибкой SDK.
+/// EN: Map a non-success HTTP status to an SDK error.
+fn error_for_status(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+    body: String,
+) -> Error {
+    let api_error = Box::new(ApiError::from_response(status, headers, body));
+    debug!("API responded with {api_error}");
+    match status {
+        reqwest::StatusCode::UNAUTHORIZED => Error::Unauthorized(api_error),
+        reqwest::StatusCode::FORBIDDEN => Error::Forbidden(api_error),
+        reqwest::StatusCode::NOT_FOUND => Error::NotFound(api_error),
+        reqwest::StatusCode::TOO_MANY_REQUESTS => Error::TooManyRequests(api_error),
+        code if code.is_server_error() => Error::Server(api_error),
+        _ => Error::Api(api_error),
+    }
+}
+
+pub(crate) async fn dispatch(req: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+    req.send().await.map_err(|e| {
+        if e.is_timeout() {
+            debug!("Request timed out: {e}");
+            Error::Timeout
+        } else {
+            debug!("Network error: {e}");
+            Error::Network(e.without_url().to_string())
+        }
+    })
+}
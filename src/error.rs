@@ -1,3 +1,6 @@
+use serde::Deserialize;
+use std::fmt;
+
 /// RU: Возможные ошибки SDK.  
 /// EN: All possible errors produced by the SDK.
 #[derive(Debug, thiserror::Error)]
@@ -6,6 +9,10 @@ pub enum Error {
     #[error("configuration error: {0}")]
     Config(String),
 
+    /// RU: Запрос не прошёл проверку до отправки. EN: Payload failed validation before sending.
+    #[error("validation error: {0}")]
+    Validation(#[from] validator::ValidationErrors),
+
     /// RU: Превышено время ожидания запроса. EN: Request timed out.
     #[error("timeout")]
     Timeout,
@@ -15,28 +22,91 @@ pub enum Error {
     Network(String),
 
     /// RU: Токен недействителен или отсутствует. EN: Unauthorized (missing/invalid token).
-    #[error("unauthorized")]
-    Unauthorized,
+    #[error("unauthorized: {0}")]
+    Unauthorized(Box<ApiError>),
 
     /// RU: Недостаточно прав или запрет. EN: Forbidden.
-    #[error("forbidden")]
-    Forbidden,
+    #[error("forbidden: {0}")]
+    Forbidden(Box<ApiError>),
+
+    /// RU: Разрешение не покрывает нужные права. EN: Consent lacks required permissions.
+    #[error("consent is missing permissions: {0:?}")]
+    MissingPermissions(Vec<crate::ExternalConsentTypeEnum>),
 
     /// RU: Ресурс не найден. EN: Resource not found.
-    #[error("not found")]
-    NotFound,
+    #[error("not found: {0}")]
+    NotFound(Box<ApiError>),
 
     /// RU: Превышен лимит запросов. EN: Too many requests.
-    #[error("too many requests")]
-    TooManyRequests,
+    #[error("too many requests: {0}")]
+    TooManyRequests(Box<ApiError>),
 
     /// RU: Ошибка на стороне сервера Tochka. EN: Server-side error.
     #[error("server error: {0}")]
-    Server(String),
+    Server(Box<ApiError>),
 
     /// RU: Ошибка прикладного уровня API (код не 2xx). EN: API returned non-success response.
     #[error("api error: {0}")]
-    Api(String),
+    Api(Box<ApiError>),
+
+    /// RU: Ключ идемпотентности уже использован для другого запроса.
+    /// EN: Idempotency key was already used for a different request.
+    #[error("idempotency key {key} was already used for a different request")]
+    IdempotencyKeyReused {
+        /// RU: Ключ идемпотентности. EN: Idempotency key.
+        key: String,
+    },
+
+    /// RU: Ссылка `next` ведёт на другой хост. EN: Pagination `next` link points to another origin.
+    #[error("next page link {0} points outside the API base URL")]
+    ForeignNextLink(String),
+
+    /// RU: Банк не смог сформировать выписку. EN: Statement ended in the `Error` status.
+    #[error("statement {statement_id} failed")]
+    StatementFailed {
+        /// RU: Идентификатор выписки. EN: Statement ID.
+        statement_id: String,
+    },
+
+    /// RU: Выписка не успела сформироваться. EN: Statement was not ready in time.
+    #[error("statement {statement_id} is not ready after {waited:?}")]
+    StatementTimeout {
+        /// RU: Идентификатор выписки. EN: Statement ID.
+        statement_id: String,
+        /// RU: Сколько ждали. EN: Time waited.
+        waited: std::time::Duration,
+    },
+
+    /// RU: Сумма возврата больше доступного остатка. EN: Refund exceeds the refundable amount.
+    #[error("refund of {requested} exceeds {remaining} left on operation {operation_id}")]
+    RefundExceedsRemaining {
+        /// RU: Идентификатор операции. EN: Operation ID.
+        operation_id: String,
+        /// RU: Запрошено. EN: Requested amount.
+        requested: crate::Money,
+        /// RU: Доступно для возврата. EN: Refundable amount.
+        remaining: crate::Money,
+    },
+
+    /// RU: Файл обмена с 1С не разобран. EN: Invalid 1C exchange file.
+    #[error(
+        "invalid 1C exchange file: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    Exchange(Vec<crate::ExchangeError>),
+
+    /// RU: Подпись или содержимое вебхука некорректны. EN: Invalid webhook token.
+    #[error("invalid webhook token: {0}")]
+    InvalidToken(String),
+
+    /// RU: В успешном ответе нет ожидаемых данных. EN: Successful response lacks the expected data.
+    #[error("response has no {path}\nraw body: {raw}")]
+    MissingData {
+        /// RU: Путь в JSON, где ожидались данные. EN: JSON path of the missing data.
+        path: String,
+        /// RU: Исходное тело ответа. EN: Raw response body.
+        raw: String,
+    },
 
     /// RU: Ошибка десериализации ответа API. EN: Failed to deserialize API response.
     #[error("deserialization error at {path}: {message}\nraw body: {raw}")]
@@ -50,6 +120,150 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// RU: Тело ошибки API, если ошибка пришла от сервера. EN: API error body for non-2xx responses.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::Unauthorized(e)
+            | Self::Forbidden(e)
+            | Self::NotFound(e)
+            | Self::TooManyRequests(e)
+            | Self::Server(e)
+            | Self::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// RU: Заголовок с идентификатором запроса. EN: Response header carrying the request id.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// RU: Разобранный ответ Точки с ошибкой. EN: Parsed Tochka error response.
+///
+/// Если тело не JSON, заполнены только `status`, `request_id` и `raw`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// RU: HTTP-статус. EN: HTTP status.
+    pub status: reqwest::StatusCode,
+    /// RU: Идентификатор запроса (заголовок или поле `id`). EN: Request id (header or `id`).
+    pub request_id: Option<String>,
+    /// RU: Код ошибки. EN: Error code.
+    pub code: Option<String>,
+    /// RU: Идентификатор ошибки. EN: Error id.
+    pub id: Option<String>,
+    /// RU: Сообщение. EN: Message.
+    pub message: Option<String>,
+    /// RU: Подробности. EN: Error details.
+    pub errors: Vec<ApiErrorDetail>,
+    /// RU: Исходное тело ответа. EN: Raw response body.
+    pub raw: String,
+}
+
+/// RU: Элемент массива `Errors`. EN: Entry of the `Errors` array.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiErrorDetail {
+    /// RU: Код ошибки. EN: Error code.
+    pub error_code: String,
+    /// RU: Сообщение. EN: Message.
+    pub message: Option<String>,
+    /// RU: Ссылка на документацию. EN: Documentation URL.
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    // OAuth token endpoint отвечает в формате RFC 6749: `error` / `error_description`.
+    #[serde(alias = "error", default, deserialize_with = "string_or_number")]
+    code: Option<String>,
+    id: Option<String>,
+    #[serde(alias = "error_description")]
+    message: Option<String>,
+    #[serde(rename = "Errors", default)]
+    errors: Vec<ApiErrorDetail>,
+}
+
+/// `code` приходит то строкой (`"403"`), то числом (`403`).
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Text(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(
+        Option::<Raw>::deserialize(deserializer)?.map(|code| match code {
+            Raw::Text(text) => text,
+            Raw::Number(number) => number.to_string(),
+        }),
+    )
+}
+
+impl ApiError {
+    /// RU: Разобрать ответ с ошибкой. EN: Parse an error response.
+    pub fn from_response(
+        status: reqwest::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+        raw: String,
+    ) -> Self {
+        let body = serde_json::from_str::<ApiErrorBody>(&raw).ok();
+        let header_id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let (code, id, message, errors) = match body {
+            Some(body) => (body.code, body.id, body.message, body.errors),
+            None => (None, None, None, Vec::new()),
+        };
+
+        Self {
+            status,
+            request_id: header_id.or_else(|| id.clone()),
+            code,
+            id,
+            message,
+            errors,
+            raw,
+        }
+    }
+
+    /// RU: Коды ошибок из `Errors`. EN: Error codes from `Errors`.
+    pub fn error_codes(&self) -> impl Iterator<Item = &str> {
+        self.errors.iter().map(|e| e.error_code.as_str())
+    }
+
+    /// RU: Есть ли в ответе код ошибки. EN: Whether the response carries an error code.
+    pub fn has_error_code(&self, code: &str) -> bool {
+        self.code.as_deref() == Some(code) || self.error_codes().any(|c| c == code)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.status)?;
+        if let Some(message) = &self.message {
+            write!(f, " {message}")?;
+        }
+        for detail in &self.errors {
+            write!(f, "; {}", detail.error_code)?;
+            if let Some(message) = &detail.message {
+                write!(f, ": {message}")?;
+            }
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id {request_id})")?;
+        }
+        if self.message.is_none() && self.errors.is_empty() && !self.raw.is_empty() {
+            write!(f, ": {}", self.raw)?;
+        }
+        Ok(())
+    }
+}
+
 impl From<std::env::VarError> for Error {
     fn from(err: std::env::VarError) -> Self {
         Self::Config(err.to_string())
@@ -0,0 +1,635 @@
+use crate::{
+    Contractor, ContractorBank, CreditDebitIndicator, DateValue, Error, Money, PaymentOrderPayload,
+    Statement, TaxFields, TransactionStatement, TransationTypeCode, validate_tax_code,
+};
+use chrono::NaiveDate;
+use encoding_rs::{IBM866, WINDOWS_1251};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fmt;
+use validator::Validate;
+
+/// RU: Версия формата обмена с 1С. EN: 1C exchange format version.
+pub const ONEC_FORMAT_VERSION: &str = "1.03";
+
+const ONEC_DATE_FORMAT: &str = "%d.%m.%Y";
+const DEFAULT_DOCUMENT_KIND: &str = "Платежное поручение";
+const PURPOSE_LINES: [&str; 6] = [
+    "НазначениеПлатежа1",
+    "НазначениеПлатежа2",
+    "НазначениеПлатежа3",
+    "НазначениеПлатежа4",
+    "НазначениеПлатежа5",
+    "НазначениеПлатежа6",
+];
+/// Поля [`PaymentOrderPayload`] и ключи 1С, из которых они заполняются.
+const PAYMENT_ORDER_KEYS: [(&str, &[&str]); 9] = [
+    ("account_code", &["ПлательщикСчет", "ПлательщикРасчСчет"]),
+    ("bank_code", &["ПлательщикБИК"]),
+    ("counterparty_bank_bic", &["ПолучательБИК"]),
+    (
+        "counterparty_account_number",
+        &["ПолучательСчет", "ПолучательРасчСчет"],
+    ),
+    ("counterparty_inn", &["ПолучательИНН"]),
+    ("counterparty_kpp", &["ПолучательКПП"]),
+    ("counterparty_name", &["Получатель1", "Получатель"]),
+    ("payment_amount", &["Сумма"]),
+    ("payment_purpose", &["НазначениеПлатежа"]),
+];
+const CSV_HEADER: [&str; 20] = [
+    "transaction_id",
+    "payment_id",
+    "document_number",
+    "document_process_date",
+    "direction",
+    "amount",
+    "currency",
+    "payer_name",
+    "payer_inn",
+    "payer_kpp",
+    "payer_account",
+    "payer_bank",
+    "payer_bic",
+    "recipient_name",
+    "recipient_inn",
+    "recipient_kpp",
+    "recipient_account",
+    "recipient_bank",
+    "recipient_bic",
+    "purpose",
+];
+
+impl Statement {
+    /// Метод для выгрузки выписки в формате 1CClientBankExchange
+    ///
+    /// Возвращает текст с переводами строк CRLF. Для записи в файл используйте
+    /// [`Statement::to_1c_bytes`], 1С ожидает кодировку windows-1251.
+    pub fn to_1c_exchange(&self) -> String {
+        let account = self.account_number();
+        let start = self.start_date_time.format(ONEC_DATE_FORMAT).to_string();
+        let end = self.end_date_time.format(ONEC_DATE_FORMAT).to_string();
+        let transactions = self.transaction.as_deref().unwrap_or_default();
+
+        // Выписка по одному счёту, поэтому валюты операций не сверяются.
+        let (credited, debited) =
+            transactions
+                .iter()
+                .fold((Decimal::ZERO, Decimal::ZERO), |(credited, debited), tx| {
+                    let amount = tx.subfields.amount.amount.amount();
+                    match tx.credit_debit_indicator {
+                        CreditDebitIndicator::Credit => (credited + amount, debited),
+                        CreditDebitIndicator::Debit => (credited, debited + amount),
+                    }
+                });
+
+        let mut out = OneCWriter::default();
+        out.line("1CClientBankExchange");
+        out.field("ВерсияФормата", ONEC_FORMAT_VERSION);
+        out.field("Кодировка", "Windows");
+        out.field("Отправитель", "Точка");
+        out.field("Получатель", "Бухгалтерский учет");
+        out.field(
+            "ДатаСоздания",
+            &self.creation_date_time.format(ONEC_DATE_FORMAT).to_string(),
+        );
+        out.field(
+            "ВремяСоздания",
+            &self.creation_date_time.format("%H:%M:%S").to_string(),
+        );
+        out.field("ДатаНачала", &start);
+        out.field("ДатаКонца", &end);
+        out.field("РасчСчет", account);
+
+        out.line("СекцияРасчСчет");
+        out.field("ДатаНачала", &start);
+        out.field("ДатаКонца", &end);
+        out.field("РасчСчет", account);
+        if let Some(balance) = self.start_date_balance {
+            out.field("НачальныйОстаток", &balance.to_string());
+        }
+        out.field("ВсегоПоступило", &Money::from(credited).to_string());
+        out.field("ВсегоСписано", &Money::from(debited).to_string());
+        if let Some(balance) = self.end_date_balance {
+            out.field("КонечныйОстаток", &balance.to_string());
+        }
+        out.line("КонецРасчСчет");
+
+        for tx in transactions {
+            out.document(tx);
+        }
+
+        out.line("КонецФайла");
+        out.0
+    }
+
+    /// Метод для выгрузки выписки в 1С в кодировке windows-1251
+    ///
+    /// Символы, которых нет в windows-1251, заменяются на HTML-сущности.
+    pub fn to_1c_bytes(&self) -> Vec<u8> {
+        WINDOWS_1251.encode(&self.to_1c_exchange()).0.into_owned()
+    }
+
+    /// Метод для выгрузки транзакций выписки в CSV
+    ///
+    /// Одна строка на транзакцию, первая строка — заголовок. Кодировка UTF-8,
+    /// разделитель — запятая, переводы строк CRLF (RFC 4180).
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        push_csv_record(&mut out, CSV_HEADER.iter().copied());
+
+        for tx in self.transaction.as_deref().unwrap_or_default() {
+            let fields = &tx.subfields;
+            let direction = match tx.credit_debit_indicator {
+                CreditDebitIndicator::Credit => "credit",
+                CreditDebitIndicator::Debit => "debit",
+            };
+            let date = tx.document_process_date.map(|date| date.to_string());
+            let amount = fields.amount.amount.to_string();
+
+            push_csv_record(
+                &mut out,
+                [
+                    opt(&tx.transaction_id),
+                    opt(&tx.payment_id),
+                    opt(&tx.document_number),
+                    date.as_deref().unwrap_or_default(),
+                    direction,
+                    amount.as_str(),
+                    fields.amount.currency().alpha_code(),
+                    opt(&fields.debtor_party.name),
+                    opt(&fields.debtor_party.inn),
+                    opt(&fields.debtor_party.kpp),
+                    opt(&fields.debtor_account.identification),
+                    opt(&fields.debtor_agent.name),
+                    opt(&fields.debtor_agent.identification),
+                    opt(&fields.creditor_party.name),
+                    opt(&fields.creditor_party.inn),
+                    opt(&fields.creditor_party.kpp),
+                    opt(&fields.creditor_account.identification),
+                    opt(&fields.creditor_agent.name),
+                    opt(&fields.creditor_agent.identification),
+                    opt(&tx.description),
+                ],
+            );
+        }
+
+        out
+    }
+
+    /// Номер счёта без БИК: `accountId` приходит как `счёт/БИК`.
+    fn account_number(&self) -> &str {
+        self.account_id
+            .split_once('/')
+            .map_or(self.account_id.as_str(), |(account, _)| account)
+    }
+}
+
+#[derive(Default)]
+struct OneCWriter(String);
+
+impl OneCWriter {
+    fn line(&mut self, line: &str) {
+        self.0.push_str(line);
+        self.0.push_str("\r\n");
+    }
+
+    fn field(&mut self, key: &str, value: &str) {
+        self.0.push_str(key);
+        self.0.push('=');
+        // Значение не может переносить строку: 1С читает файл построчно.
+        self.0.extend(
+            value
+                .chars()
+                .map(|c| if c == '\r' || c == '\n' { ' ' } else { c }),
+        );
+        self.0.push_str("\r\n");
+    }
+
+    fn document(&mut self, tx: &TransactionStatement) {
+        let fields = &tx.subfields;
+        let kind = tx
+            .transaction_type_code
+            .as_ref()
+            .map_or(DEFAULT_DOCUMENT_KIND, TransationTypeCode::as_str);
+        let date = tx
+            .document_process_date
+            .map(|date| date.format(ONEC_DATE_FORMAT).to_string())
+            .unwrap_or_default();
+
+        self.field("СекцияДокумент", kind);
+        self.field("Номер", opt(&tx.document_number));
+        self.field("Дата", &date);
+        self.field("Сумма", &fields.amount.amount.to_string());
+        self.party(
+            "Плательщик",
+            &fields.debtor_party,
+            fields.debtor_account.identification.as_deref(),
+            &fields.debtor_agent,
+        );
+        self.party(
+            "Получатель",
+            &fields.creditor_party,
+            fields.creditor_account.identification.as_deref(),
+            &fields.creditor_agent,
+        );
+        match tx.credit_debit_indicator {
+            CreditDebitIndicator::Credit => self.field("ДатаПоступило", &date),
+            CreditDebitIndicator::Debit => self.field("ДатаСписано", &date),
+        }
+        self.field("ВидОплаты", "01");
+        self.tax(&fields.tax_fields);
+        self.field("НазначениеПлатежа", opt(&tx.description));
+        self.line("КонецДокумента");
+    }
+
+    fn party(
+        &mut self,
+        role: &str,
+        party: &Contractor,
+        account: Option<&str>,
+        bank: &ContractorBank,
+    ) {
+        let account = account.unwrap_or_default();
+        self.field(&format!("{role}Счет"), account);
+        self.field(role, opt(&party.name));
+        self.field(&format!("{role}ИНН"), opt(&party.inn));
+        self.field(&format!("{role}КПП"), opt(&party.kpp));
+        self.field(&format!("{role}РасчСчет"), account);
+        self.field(&format!("{role}Банк1"), opt(&bank.name));
+        self.field(&format!("{role}БИК"), opt(&bank.identification));
+        self.field(&format!("{role}Корсчет"), opt(&bank.account_identification));
+    }
+
+    /// Бюджетные реквизиты выгружаются, только если заполнен статус составителя (поле 101).
+    fn tax(&mut self, tax: &TaxFields) {
+        let Some(status) = tax.originator_status.as_deref() else {
+            return;
+        };
+        let document_date = match &tax.document_date {
+            Some(DateValue::Text(text)) => text.clone(),
+            Some(DateValue::Number(number)) => number.to_string(),
+            None => String::new(),
+        };
+
+        self.field("СтатусСоставителя", status);
+        self.field("ПоказательКБК", opt(&tax.kbk));
+        self.field("ОКАТО", opt(&tax.oktmo));
+        self.field("ПоказательОснования", opt(&tax.base));
+        self.field("ПоказательПериода", opt(&tax.field107));
+        self.field("ПоказательНомера", opt(&tax.document_number));
+        self.field("ПоказательДаты", &document_date);
+        self.field("ПоказательТипа", opt(&tax.type_));
+    }
+}
+
+fn opt(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or_default()
+}
+
+fn push_csv_record<'a>(out: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+/// RU: Ошибка в строке файла 1С. EN: Error at a line of a 1C exchange file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeError {
+    /// RU: Номер строки (с единицы). EN: One-based line number.
+    pub line: usize,
+    /// RU: Ключ 1С, к которому относится ошибка. EN: 1C key the error refers to.
+    pub field: Option<String>,
+    /// RU: Описание ошибки. EN: Error message.
+    pub message: String,
+}
+
+impl ExchangeError {
+    fn new(line: usize, field: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            field: field.map(str::to_owned),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "line {}: {field}: {}", self.line, self.message),
+            None => write!(f, "line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+impl PaymentOrderPayload {
+    /// Метод для разбора платёжных поручений из файла 1CClientBankExchange
+    ///
+    /// Файл читается в windows-1251, при `Кодировка=DOS` — в cp866.
+    pub fn from_1c_bytes(bytes: &[u8]) -> Result<Vec<Self>, Error> {
+        // Заголовок `Кодировка` читается только в той кодировке, которую объявляет.
+        let (dos, _, _) = IBM866.decode(bytes);
+        if dos.lines().any(|line| line.trim() == "Кодировка=DOS") {
+            return Self::from_1c_exchange(&dos);
+        }
+        Self::from_1c_exchange(&WINDOWS_1251.decode(bytes).0)
+    }
+
+    /// Метод для разбора платёжных поручений из текста 1CClientBankExchange
+    ///
+    /// Ошибки собираются по всему файлу и возвращаются разом в [`Error::Exchange`].
+    /// Секция `СекцияРасчСчет` пропускается, документы других видов считаются ошибкой.
+    pub fn from_1c_exchange(text: &str) -> Result<Vec<Self>, Error> {
+        let mut orders = Vec::new();
+        let mut errors = Vec::new();
+        let mut document: Option<OneCDocument> = None;
+        let mut started = false;
+        let mut finished = false;
+        let mut in_account_section = false;
+        let mut last_line = 0;
+
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let entry = raw.trim_start_matches('\u{feff}').trim();
+            if entry.is_empty() {
+                continue;
+            }
+            last_line = line;
+
+            if !started {
+                if entry != "1CClientBankExchange" {
+                    errors.push(ExchangeError::new(
+                        line,
+                        None,
+                        "expected 1CClientBankExchange header",
+                    ));
+                    return Err(Error::Exchange(errors));
+                }
+                started = true;
+                continue;
+            }
+            if finished {
+                errors.push(ExchangeError::new(line, None, "data after КонецФайла"));
+                break;
+            }
+
+            let (key, value) = match entry.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (entry, ""),
+            };
+            match key {
+                "СекцияРасчСчет" => in_account_section = true,
+                "КонецРасчСчет" => in_account_section = false,
+                _ if in_account_section => {}
+                "СекцияДокумент" => {
+                    if let Some(open) = document.take() {
+                        errors.push(open.unclosed());
+                    }
+                    if value != DEFAULT_DOCUMENT_KIND {
+                        errors.push(ExchangeError::new(
+                            line,
+                            Some(key),
+                            format!("unsupported document kind {value:?}"),
+                        ));
+                    }
+                    document = Some(OneCDocument::new(line));
+                }
+                "КонецДокумента" => match document.take() {
+                    Some(open) => match open.into_payment_order(line) {
+                        Ok(order) => orders.push(order),
+                        Err(mut invalid) => errors.append(&mut invalid),
+                    },
+                    None => errors.push(ExchangeError::new(
+                        line,
+                        None,
+                        "КонецДокумента without СекцияДокумент",
+                    )),
+                },
+                "КонецФайла" => finished = true,
+                _ if !entry.contains('=') => {
+                    errors.push(ExchangeError::new(line, None, "expected key=value"));
+                }
+                _ => {
+                    if let Some(open) = document.as_mut() {
+                        open.fields.insert(key.to_owned(), (value.to_owned(), line));
+                    }
+                }
+            }
+        }
+
+        if !started {
+            errors.push(ExchangeError::new(1, None, "file is empty"));
+        }
+        if let Some(open) = document {
+            errors.push(open.unclosed());
+        } else if started && !finished {
+            errors.push(ExchangeError::new(last_line, None, "КонецФайла is missing"));
+        }
+
+        if errors.is_empty() {
+            Ok(orders)
+        } else {
+            errors.sort_by_key(|error| error.line);
+            Err(Error::Exchange(errors))
+        }
+    }
+}
+
+/// Документ между `СекцияДокумент` и `КонецДокумента`: значения с номерами строк.
+struct OneCDocument {
+    line: usize,
+    /// Строка `КонецДокумента`, известна только у закрытого документа.
+    end: usize,
+    fields: HashMap<String, (String, usize)>,
+}
+
+impl OneCDocument {
+    fn new(line: usize) -> Self {
+        Self {
+            line,
+            end: line,
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Поля нет в документе: ошибка на строке `КонецДокумента` с диапазоном документа.
+    fn missing(&self, key: &str) -> ExchangeError {
+        ExchangeError::new(
+            self.end,
+            Some(key),
+            format!(
+                "required field is missing in the document at lines {}-{}",
+                self.line, self.end
+            ),
+        )
+    }
+
+    fn unclosed(&self) -> ExchangeError {
+        ExchangeError::new(self.line, None, "КонецДокумента is missing")
+    }
+
+    /// Непустое значение; `0` в 1С означает «не заполнено».
+    fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .get(key)
+            .map(|(value, _)| value.as_str())
+            .filter(|value| !value.is_empty() && *value != "0")
+    }
+
+    /// Значение как есть: в налоговых показателях `0` — осмысленное значение.
+    fn get_raw(&self, key: &str) -> Option<String> {
+        self.fields
+            .get(key)
+            .map(|(value, _)| value.clone())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn line_of(&self, keys: &[&str]) -> usize {
+        keys.iter()
+            .find_map(|key| self.fields.get(*key).map(|(_, line)| *line))
+            .unwrap_or(self.end)
+    }
+
+    fn required(&self, keys: &[&str], errors: &mut Vec<ExchangeError>) -> String {
+        match keys.iter().find_map(|key| self.get(key)) {
+            Some(value) => value.to_owned(),
+            None => {
+                errors.push(self.missing(keys[0]));
+                String::new()
+            }
+        }
+    }
+
+    fn into_payment_order(mut self, end: usize) -> Result<PaymentOrderPayload, Vec<ExchangeError>> {
+        self.end = end;
+        let mut errors = Vec::new();
+
+        let amount = self.required(&["Сумма"], &mut errors);
+        let payment_amount = match amount.parse::<Money>() {
+            Ok(amount) => amount,
+            Err(_) if amount.is_empty() => Money::default(),
+            Err(_) => {
+                errors.push(ExchangeError::new(
+                    self.line_of(&["Сумма"]),
+                    Some("Сумма"),
+                    format!("invalid amount {amount:?}"),
+                ));
+                Money::default()
+            }
+        };
+
+        let date = self.required(&["Дата"], &mut errors);
+        let payment_date = match NaiveDate::parse_from_str(&date, ONEC_DATE_FORMAT) {
+            Ok(date) => date,
+            Err(_) if date.is_empty() => NaiveDate::default(),
+            Err(_) => {
+                errors.push(ExchangeError::new(
+                    self.line_of(&["Дата"]),
+                    Some("Дата"),
+                    format!("invalid date {date:?}, expected ДД.ММ.ГГГГ"),
+                ));
+                NaiveDate::default()
+            }
+        };
+
+        for key in ["ПлательщикИНН", "ПолучательИНН"] {
+            if let Some(inn) = self.get(key)
+                && validate_tax_code(inn).is_err()
+            {
+                errors.push(ExchangeError::new(
+                    self.line_of(&[key]),
+                    Some(key),
+                    format!("invalid INN {inn:?}"),
+                ));
+            }
+        }
+
+        let purpose = match self.get("НазначениеПлатежа") {
+            Some(purpose) => purpose.to_owned(),
+            None => {
+                let purpose = PURPOSE_LINES
+                    .iter()
+                    .filter_map(|key| self.get(key))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if purpose.is_empty() {
+                    errors.push(self.missing("НазначениеПлатежа"));
+                }
+                purpose
+            }
+        };
+
+        let mut order = PaymentOrderPayload::new(
+            self.required(&["ПлательщикСчет", "ПлательщикРасчСчет"], &mut errors),
+            self.required(&["ПлательщикБИК"], &mut errors),
+            self.required(&["ПолучательБИК"], &mut errors),
+            self.required(&["ПолучательСчет", "ПолучательРасчСчет"], &mut errors),
+            self.required(&["Получатель1", "Получатель"], &mut errors),
+            payment_amount,
+            payment_date,
+            purpose,
+        );
+        order.counterparty_inn = self.get("ПолучательИНН").map(str::to_owned);
+        order.counterparty_kpp = self.get("ПолучательКПП").map(str::to_owned);
+        order.counterparty_bank_corr_account = self.get("ПолучательКорсчет").map(str::to_owned);
+        order.payment_number = self.get("Номер").map(str::to_owned);
+        order.payment_priority = self.get("Очередность").map(str::to_owned);
+        order.code_purpose = self.get("КодНазПлатежа").map(str::to_owned);
+        order.supplier_bill_id = self.get("Код").map(str::to_owned);
+
+        let tax = TaxFields {
+            base: self.get_raw("ПоказательОснования"),
+            document_date: self.get_raw("ПоказательДаты").map(DateValue::Text),
+            document_number: self.get_raw("ПоказательНомера"),
+            field107: self.get_raw("ПоказательПериода"),
+            kbk: self.get_raw("ПоказательКБК"),
+            oktmo: self.get_raw("ОКАТО"),
+            originator_status: self.get_raw("СтатусСоставителя"),
+            type_: self.get_raw("ПоказательТипа"),
+        };
+        if tax.originator_status.is_some() {
+            order = order.tax_fields(tax);
+        }
+
+        // Ошибки разбора уже указывают на строку, повторная валидация их бы продублировала.
+        if errors.is_empty()
+            && let Err(invalid) = order.validate()
+        {
+            for (field, field_errors) in invalid.field_errors() {
+                let keys = PAYMENT_ORDER_KEYS
+                    .iter()
+                    .find(|(name, _)| *name == field)
+                    .map_or(&[][..], |(_, keys)| *keys);
+                let key = keys
+                    .iter()
+                    .copied()
+                    .find(|key| self.fields.contains_key(*key))
+                    .or(keys.first().copied());
+                for error in field_errors {
+                    errors.push(ExchangeError::new(
+                        self.line_of(keys),
+                        key,
+                        format!("failed {} validation", error.code),
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(order)
+        } else {
+            Err(errors)
+        }
+    }
+}
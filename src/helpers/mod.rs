@@ -1,3 +1,5 @@
+use crate::Money;
+use rust_decimal::Decimal;
 use validator::ValidationError;
 
 /// RU: Проверка телефонного номера: 11–15 символов, допускается ведущий '+'.  
@@ -36,3 +38,23 @@ pub fn validate_tax_code(tax: &str) -> Result<(), ValidationError> {
 
     Ok(())
 }
+
+/// RU: Проверка, что сумма больше нуля.  
+/// EN: Validate that a money amount is positive.
+pub fn validate_positive_amount(amount: &Money) -> Result<(), ValidationError> {
+    if !amount.is_positive() {
+        return Err(ValidationError::new("amount_positive"));
+    }
+
+    Ok(())
+}
+
+/// RU: Проверка, что количество больше нуля.  
+/// EN: Validate that a quantity is positive.
+pub fn validate_positive_quantity(quantity: &Decimal) -> Result<(), ValidationError> {
+    if !quantity.is_sign_positive() || quantity.is_zero() {
+        return Err(ValidationError::new("quantity_positive"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use crate::Error;
+use async_trait::async_trait;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+use tokio::time::Instant;
+use uuid::Uuid;
+
+/// RU: Ключ идемпотентности запроса. EN: Request idempotency key.
+///
+/// Уходит в заголовке [`IDEMPOTENCY_KEY_HEADER`](crate::IDEMPOTENCY_KEY_HEADER). Один ключ —
+/// одна логическая операция: сохраните его (например, рядом с заказом) и передавайте тот же
+/// ключ при повторе, тогда платёж или возврат не будет создан дважды.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// RU: Новый случайный ключ (UUID v4). EN: New random key (UUID v4).
+    pub fn new() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for IdempotencyKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<String> for IdempotencyKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl From<&str> for IdempotencyKey {
+    fn from(key: &str) -> Self {
+        Self(key.to_string())
+    }
+}
+
+impl From<Uuid> for IdempotencyKey {
+    fn from(key: Uuid) -> Self {
+        Self(key.to_string())
+    }
+}
+
+impl fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// RU: Сохранённый ответ и отпечаток запроса, на который он получен.
+/// EN: Stored response together with the fingerprint of its request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredResponse {
+    /// RU: SHA-256 от метода, URL и тела запроса. EN: SHA-256 of request method, URL and body.
+    pub fingerprint: String,
+    /// RU: Тело успешного ответа. EN: Successful response body.
+    pub body: Vec<u8>,
+}
+
+/// RU: Отпечаток запроса для сверки повторов. EN: Request fingerprint used to check replays.
+pub(crate) fn request_fingerprint(request: &reqwest::Request) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(request.method().as_str());
+    hasher.update(b"\n");
+    hasher.update(request.url().as_str());
+    hasher.update(b"\n");
+    if let Some(body) = request.body().and_then(reqwest::Body::as_bytes) {
+        hasher.update(body);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// RU: Хранилище ответов по ключам идемпотентности. EN: Response store keyed by idempotency key.
+///
+/// Клиент кладёт сюда тело успешного ответа вместе с отпечатком запроса и при повторе
+/// операции с тем же ключом возвращает его, не отправляя запрос. Если тот же ключ пришёл с
+/// другим запросом, возвращается [`Error::IdempotencyKeyReused`]. Ошибки не сохраняются:
+/// повтор после ошибки уходит на сервер с тем же заголовком. Ошибка записи в хранилище
+/// только логируется — операция на сервере уже выполнена.
+///
+/// Хранилище не блокирует ключ на время запроса: два одновременных вызова с одним ключом
+/// оба уйдут на сервер, и от двойного списания защищает только то, что Точка учитывает
+/// заголовок `Idempotency-Key`.
+///
+/// Для нескольких процессов реализуйте трейт поверх общей базы (Redis, Postgres); в одном
+/// процессе хватит [`InMemoryIdempotencyStore`].
+#[async_trait]
+pub trait IdempotencyStore: fmt::Debug + Send + Sync {
+    /// RU: Сохранённый ответ. EN: Stored response.
+    async fn get(&self, key: &IdempotencyKey) -> Result<Option<StoredResponse>, Error>;
+
+    /// RU: Сохранить успешный ответ. EN: Store a successful response.
+    async fn put(&self, key: &IdempotencyKey, response: StoredResponse) -> Result<(), Error>;
+}
+
+/// RU: Время жизни записей по умолчанию. EN: Default entry lifetime.
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// RU: Хранилище ответов в памяти процесса. EN: In-process response store.
+#[derive(Debug)]
+pub struct InMemoryIdempotencyStore {
+    ttl: Duration,
+    entries: Mutex<HashMap<IdempotencyKey, (Instant, StoredResponse)>>,
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_IDEMPOTENCY_TTL,
+            entries: Mutex::default(),
+        }
+    }
+}
+
+impl InMemoryIdempotencyStore {
+    /// RU: Хранилище с TTL [`DEFAULT_IDEMPOTENCY_TTL`]. EN: Store with the default TTL.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// RU: Сколько ответов сейчас хранится. EN: Number of stored responses.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn get(&self, key: &IdempotencyKey) -> Result<Option<StoredResponse>, Error> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.get(key) {
+            Some((stored, _)) if stored.elapsed() >= self.ttl => {
+                debug!("Idempotency key {key} expired");
+                entries.remove(key);
+                Ok(None)
+            }
+            entry => Ok(entry.map(|(_, response)| response.clone())),
+        }
+    }
+
+    async fn put(&self, key: &IdempotencyKey, response: StoredResponse) -> Result<(), Error> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let ttl = self.ttl;
+        entries.retain(|_, (stored, _)| stored.elapsed() < ttl);
+        entries.insert(key.clone(), (Instant::now(), response));
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{Error, Jwk};
+use async_trait::async_trait;
+use log::debug;
+use serde::Deserialize;
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// RU: Адрес публичных ключей Точки для проверки вебхуков. EN: Tochka webhook public keys URL.
+pub const JWKS_URL: &str = "https://enter.tochka.com/doc/openapi/static/keys/public";
+
+/// RU: Сколько ключи живут в кэше по умолчанию. EN: Default JWKS cache TTL.
+pub const DEFAULT_JWKS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// RU: Минимальный интервал между обновлениями из-за неизвестного `kid`.
+/// EN: Default minimum interval between refreshes triggered by an unknown `kid`.
+pub const DEFAULT_JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// RU: Набор публичных ключей. EN: Set of public keys.
+///
+/// Принимает и `{"keys": [...]}`, и одиночный JWK — Точка сейчас отдаёт один ключ.
+#[derive(Debug, Clone, Default)]
+pub struct JwkSet {
+    /// RU: Ключи. EN: Keys.
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// RU: Ключи-кандидаты для `kid` (все ключи, если `kid` не указан).
+    /// EN: Candidate keys for a `kid` (all keys when the token has none).
+    pub fn find(&self, kid: Option<&str>) -> Vec<&Jwk> {
+        match kid {
+            Some(kid) => self
+                .keys
+                .iter()
+                .filter(|jwk| jwk.kid.as_deref() == Some(kid))
+                .collect(),
+            None => self.keys.iter().collect(),
+        }
+    }
+}
+
+impl From<Jwk> for JwkSet {
+    fn from(jwk: Jwk) -> Self {
+        Self { keys: vec![jwk] }
+    }
+}
+
+impl<'de> Deserialize<'de> for JwkSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Set { keys: Vec<Jwk> },
+            Single(Jwk),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Set { keys } => Self { keys },
+            Raw::Single(jwk) => jwk.into(),
+        })
+    }
+}
+
+/// RU: Источник ключей для [`JwkCache`]. EN: Key source backing a [`JwkCache`].
+#[async_trait]
+pub trait JwkSource: fmt::Debug + Send + Sync {
+    /// RU: Загрузить актуальный набор ключей. EN: Load the current key set.
+    async fn fetch(&self) -> Result<JwkSet, Error>;
+}
+
+/// RU: Ключи по HTTP (по умолчанию — с сайта Точки). EN: Keys fetched over HTTP.
+#[derive(Debug, Clone)]
+pub struct HttpJwkSource {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl HttpJwkSource {
+    /// RU: Источник по произвольному адресу. EN: Source at a custom URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+impl Default for HttpJwkSource {
+    fn default() -> Self {
+        Self::new(JWKS_URL)
+    }
+}
+
+#[async_trait]
+impl JwkSource for HttpJwkSource {
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        debug!("Fetching JWKS from {}", self.url);
+        self.http
+            .get(&self.url)
+            .send()
+            .await
+            .map_err(|e| Error::Config(e.to_string()))?
+            .json::<JwkSet>()
+            .await
+            .map_err(|e| Error::Config(e.to_string()))
+    }
+}
+
+/// RU: Фиксированный набор ключей (тесты, офлайн). EN: Fixed key set (tests, offline use).
+#[derive(Debug, Clone)]
+pub struct StaticJwkSource(pub JwkSet);
+
+#[async_trait]
+impl JwkSource for StaticJwkSource {
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        Ok(self.0.clone())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Cached {
+    set: JwkSet,
+    fetched_at: Option<Instant>,
+    failed_at: Option<Instant>,
+}
+
+/// RU: Кэш публичных ключей с TTL и обновлением при ротации.
+/// EN: Public key cache with TTL and refresh on key rotation.
+///
+/// Устаревший по TTL кэш обновляется перед проверкой вебхука. Токен с неизвестным `kid`
+/// (или с неподошедшей подписью) вызывает внеплановое обновление, но не чаще, чем раз
+/// в `refresh_interval`. После неудачной загрузки источник тоже не запрашивается раньше
+/// `refresh_interval`, а проверка идёт по старым ключам.
+#[derive(Debug)]
+pub struct JwkCache {
+    source: Arc<dyn JwkSource>,
+    ttl: Duration,
+    refresh_interval: Duration,
+    state: RwLock<Cached>,
+    refresh_lock: Mutex<()>,
+}
+
+impl JwkCache {
+    /// RU: Пустой кэш поверх источника. EN: Empty cache over a source.
+    pub fn new(source: impl JwkSource + 'static) -> Self {
+        Self {
+            source: Arc::new(source),
+            ttl: DEFAULT_JWKS_TTL,
+            refresh_interval: DEFAULT_JWKS_REFRESH_INTERVAL,
+            state: RwLock::new(Cached::default()),
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    /// RU: Сколько ключи считаются свежими. EN: How long loaded keys stay fresh.
+    ///
+    /// По умолчанию [`DEFAULT_JWKS_TTL`]. После TTL ключи перечитываются перед
+    /// следующей проверкой вебхука.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// RU: Минимальный интервал между внеплановыми загрузками. EN: Minimum interval between
+    /// unscheduled reloads.
+    ///
+    /// По умолчанию [`DEFAULT_JWKS_REFRESH_INTERVAL`]. Ограничивает обновления из-за
+    /// неизвестного `kid` и повторы после неудачной загрузки, чтобы поддельные или частые
+    /// вебхуки не превращались в поток запросов к источнику.
+    pub fn refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// RU: Текущий набор ключей. EN: Snapshot of the cached keys.
+    pub fn keys(&self) -> JwkSet {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .set
+            .clone()
+    }
+
+    /// RU: Истёк ли TTL. EN: Whether the TTL has elapsed.
+    pub fn is_stale(&self) -> bool {
+        self.elapsed().is_none_or(|elapsed| elapsed >= self.ttl)
+    }
+
+    /// RU: Принудительно перечитать ключи. EN: Force a reload from the source.
+    pub async fn refresh(&self) -> Result<(), Error> {
+        let _guard = self.refresh_lock.lock().await;
+        self.reload().await
+    }
+
+    /// RU: Обновить ключи, если истёк TTL. EN: Reload keys if the TTL has elapsed.
+    ///
+    /// Если прошлая загрузка упала меньше `refresh_interval` назад, источник не
+    /// запрашивается и остаются прежние ключи.
+    pub async fn ensure_fresh(&self) -> Result<(), Error> {
+        if !self.is_stale() {
+            return Ok(());
+        }
+        let _guard = self.refresh_lock.lock().await;
+        if !self.is_stale() {
+            return Ok(());
+        }
+        if self
+            .since_failure()
+            .is_some_and(|elapsed| elapsed < self.refresh_interval)
+        {
+            debug!(
+                "JWKS reload skipped: last attempt failed less than {:?} ago",
+                self.refresh_interval
+            );
+            return Ok(());
+        }
+        self.reload().await
+    }
+
+    /// RU: Внеплановое обновление с ограничением частоты. `false` — обновление пропущено.
+    /// EN: Rate-limited refresh. Returns `false` when skipped.
+    pub async fn refresh_if_allowed(&self) -> Result<bool, Error> {
+        let _guard = self.refresh_lock.lock().await;
+        let since_attempt = match (self.elapsed(), self.since_failure()) {
+            (Some(fetched), Some(failed)) => Some(fetched.min(failed)),
+            (fetched, failed) => fetched.or(failed),
+        };
+        if since_attempt.is_some_and(|elapsed| elapsed < self.refresh_interval) {
+            debug!(
+                "JWKS refresh skipped: refreshed less than {:?} ago",
+                self.refresh_interval
+            );
+            return Ok(false);
+        }
+        self.reload().await?;
+
+        Ok(true)
+    }
+
+    fn elapsed(&self) -> Option<Duration> {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .fetched_at
+            .map(|at| at.elapsed())
+    }
+
+    fn since_failure(&self) -> Option<Duration> {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .failed_at
+            .map(|at| at.elapsed())
+    }
+
+    async fn reload(&self) -> Result<(), Error> {
+        let set = match self.source.fetch().await {
+            Ok(set) => set,
+            Err(e) => {
+                self.state
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .failed_at = Some(Instant::now());
+                return Err(e);
+            }
+        };
+        debug!(
+            "Loaded {} JWK(s) with kids {:?}",
+            set.keys.len(),
+            set.keys
+                .iter()
+                .map(|k| k.kid.as_deref())
+                .collect::<Vec<_>>()
+        );
+        *self.state.write().unwrap_or_else(|e| e.into_inner()) = Cached {
+            set,
+            fetched_at: Some(Instant::now()),
+            failed_at: None,
+        };
+
+        Ok(())
+    }
+}
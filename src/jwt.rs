@@ -1,6 +1,8 @@
-use crate::{Client, Error};
-use jsonwebtoken::{Algorithm, DecodingKey, TokenData, Validation, decode};
-use log::debug;
+use crate::{Client, Error, HttpJwkSource, JwkSource, WebhookEvent};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, TokenData, Validation, decode, decode_header, errors::ErrorKind,
+};
+use log::{debug, warn};
 use serde::{Deserialize, de::DeserializeOwned};
 
 /// Токен Точки, использующийся в вебхуках
@@ -13,31 +15,83 @@ pub struct Jwk {
     pub alg: Option<String>,
 }
 
+/// Метод для загрузки публичного ключа Точки
+///
+/// Ходит в сеть при каждом вызове и возвращает только первый ключ набора. Для проверки
+/// вебхуков используйте [`JwkCache`](crate::JwkCache): он кэширует ключи и обновляет их при ротации.
+#[deprecated(note = "use JwkCache")]
 pub async fn fetch_jwk() -> Result<Jwk, Error> {
     debug!("Fetching JWK from Tochka public keys endpoint");
-    let resp = reqwest::get("https://enter.tochka.com/doc/openapi/static/keys/public")
-        .await
-        .map_err(|e| Error::Config(e.to_string()))?
-        .json::<Jwk>()
-        .await
-        .map_err(|e| Error::Config(e.to_string()))?;
-    debug!("Successfully fetched JWK");
-
-    Ok(resp)
+    HttpJwkSource::default()
+        .fetch()
+        .await?
+        .keys
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Config(String::from("JWKS response contains no keys")))
 }
+
 impl Client {
+    /// Метод для проверки подписи токена по закэшированным ключам
+    ///
+    /// Ключ выбирается по `kid` из заголовка токена, без `kid` перебираются все ключи.
+    /// Кэш здесь не обновляется — для этого есть [`Client::decode_webhook`].
     pub fn decode_token<T>(&self, token: &str) -> jsonwebtoken::errors::Result<TokenData<T>>
     where
         T: DeserializeOwned,
     {
         debug!("Decoding webhook token");
-        let key = DecodingKey::from_rsa_components(&self.jwk.n, &self.jwk.e)?;
+        let header = decode_header(token)?;
+        let keys = self.jwks.keys();
         let mut validation = Validation::new(Algorithm::RS256);
         validation.validate_exp = false;
         validation.validate_nbf = false;
         validation.required_spec_claims.clear();
-        let decoded = decode::<T>(token, &key, &validation)?;
-        debug!("Token decoded successfully");
-        Ok(decoded)
+
+        let mut result = Err(ErrorKind::InvalidSignature.into());
+        for jwk in keys.find(header.kid.as_deref()) {
+            let key = DecodingKey::from_rsa_components(&jwk.n, &jwk.e)?;
+            result = decode::<T>(token, &key, &validation);
+            if !matches!(&result, Err(e) if *e.kind() == ErrorKind::InvalidSignature) {
+                break;
+            }
+        }
+        if result.is_ok() {
+            debug!("Token decoded successfully");
+        }
+        result
+    }
+
+    /// Метод для проверки подписи вебхука и разбора его по типу
+    ///
+    /// Тип определяется по полю `webhookType`, угадывать параметр `decode_token` не нужно.
+    /// Если ключ не подошёл (например, Точка сменила ключ), кэш ключей обновляется.
+    pub async fn decode_webhook(&self, token: &str) -> Result<WebhookEvent, Error> {
+        self.verify_webhook(token)
+            .await
+            .map_err(|e| Error::InvalidToken(e.to_string()))
+    }
+
+    pub(crate) async fn verify_webhook(
+        &self,
+        token: &str,
+    ) -> jsonwebtoken::errors::Result<WebhookEvent> {
+        if let Err(e) = self.jwks.ensure_fresh().await {
+            warn!("Failed to refresh stale JWKS, using cached keys: {e}");
+        }
+        match self.decode_token::<WebhookEvent>(token) {
+            Err(e) if *e.kind() == ErrorKind::InvalidSignature => {
+                match self.jwks.refresh_if_allowed().await {
+                    Ok(true) => self.decode_token(token),
+                    Ok(false) => Err(e),
+                    Err(refresh) => {
+                        warn!("Failed to refresh JWKS: {refresh}");
+                        Err(e)
+                    }
+                }
+            }
+            result => result,
+        }
+        .map(|decoded| decoded.claims)
     }
 }
@@ -1,14 +1,34 @@
 // #![warn(missing_docs)]
 
+mod auth;
 mod client;
 mod error;
+mod exchange;
 mod helpers;
+mod idempotency;
+mod jwks;
 mod jwt;
 mod methods;
+mod rate_limit;
+#[cfg(feature = "axum")]
+mod receiver;
+mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod types;
 
+pub use auth::*;
 pub use client::*;
 pub use error::*;
+pub use exchange::*;
 pub use helpers::*;
+pub use idempotency::*;
+pub use jwks::*;
 pub use jwt::*;
+pub use rate_limit::*;
+#[cfg(feature = "axum")]
+pub use receiver::*;
+pub use retry::*;
+#[cfg(feature = "testing")]
+pub use testing::*;
 pub use types::*;
@@ -0,0 +1,85 @@
+use crate::{
+    ApiVersion, Client, Consent, ConsentPageData, ConsentPayload, Data, Error,
+    ExternalConsentTypeEnum, PayloadWrapper, ResultBody, Service,
+};
+use log::debug;
+
+impl Client {
+    /// Метод для создания разрешения
+    ///
+    /// Разрешение создаётся в статусе *AwaitingAuthorisation*, подписать его должен пользователь.
+    pub async fn create_consent(&self, payload: ConsentPayload) -> Result<Data<Consent>, Error> {
+        debug!("Creating consent with payload: {:?}", payload);
+        self.send::<Data<Consent>>(
+            self.client
+                .post(self.url(Service::Consent, ApiVersion::V1_0, "consents"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения списка разрешений
+    pub async fn get_consents_list(&self) -> Result<Data<ConsentPageData>, Error> {
+        debug!("Fetching consents list");
+        self.send::<Data<ConsentPageData>>(self.client.get(self.url(
+            Service::Consent,
+            ApiVersion::V1_0,
+            "consents",
+        )))
+        .await
+    }
+
+    /// Метод для получения информации о конкретном разрешении
+    pub async fn get_consent(&self, consent_id: &str) -> Result<Data<Consent>, Error> {
+        debug!("Fetching consent {consent_id}");
+        self.send::<Data<Consent>>(self.client.get(self.url(
+            Service::Consent,
+            ApiVersion::V1_0,
+            format!("consents/{consent_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения всех дочерних разрешений
+    pub async fn get_child_consents(
+        &self,
+        consent_id: &str,
+    ) -> Result<Data<ConsentPageData>, Error> {
+        debug!("Fetching child consents of {consent_id}");
+        self.send::<Data<ConsentPageData>>(self.client.get(self.url(
+            Service::Consent,
+            ApiVersion::V1_0,
+            format!("consents/{consent_id}/child").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для отзыва разрешения
+    pub async fn revoke_consent(&self, consent_id: &str) -> Result<Data<ResultBody>, Error> {
+        debug!("Revoking consent {consent_id}");
+        self.send::<Data<ResultBody>>(self.client.delete(self.url(
+            Service::Consent,
+            ApiVersion::V1_0,
+            format!("consents/{consent_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Проверить, что разрешение активно и покрывает нужные права
+    ///
+    /// Позволяет упасть заранее с [`Error::MissingPermissions`] вместо `Forbidden` от API.
+    pub async fn require_permissions(
+        &self,
+        consent_id: &str,
+        required: &[ExternalConsentTypeEnum],
+    ) -> Result<Consent, Error> {
+        let consent = self.get_consent(consent_id).await?.data;
+        let missing = consent.missing(required);
+        if !missing.is_empty() {
+            debug!("Consent {consent_id} is missing permissions: {:?}", missing);
+            return Err(Error::MissingPermissions(missing));
+        }
+
+        Ok(consent)
+    }
+}
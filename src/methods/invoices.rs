@@ -0,0 +1,163 @@
+use crate::{
+    ApiVersion, BillPayload, Client, ClosingDocumentPayload, Data, Error, InvoiceDocumentCreated,
+    InvoiceEmailPayload, InvoicePaymentStatusInfo, PayloadWrapper, ResultBody, Service,
+};
+use log::debug;
+
+impl Client {
+    /// Метод для создания счёта на оплату
+    pub async fn create_bill(
+        &self,
+        payload: BillPayload,
+    ) -> Result<Data<InvoiceDocumentCreated>, Error> {
+        debug!("Creating bill with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<InvoiceDocumentCreated>>(
+            self.client
+                .post(self.url(Service::Invoice, ApiVersion::V1_0, "bills"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для удаления счёта на оплату
+    pub async fn delete_bill(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Deleting bill {document_id} of {customer_code}");
+        self.send::<Data<ResultBody>>(self.client.delete(self.url(
+            Service::Invoice,
+            ApiVersion::V1_0,
+            format!("bills/{customer_code}/{document_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для отправки счёта на оплату на email покупателя
+    pub async fn send_bill_to_email(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+        email: impl Into<String>,
+    ) -> Result<Data<ResultBody>, Error> {
+        let payload = InvoiceEmailPayload {
+            email: email.into(),
+        };
+        debug!("Sending bill {document_id} to {}", payload.email);
+        self.validate_payload(&payload)?;
+        self.send::<Data<ResultBody>>(
+            self.client
+                .post(self.url(
+                    Service::Invoice,
+                    ApiVersion::V1_0,
+                    format!("bills/{customer_code}/{document_id}/email").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения PDF-файла счёта на оплату
+    pub async fn get_bill_file(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+    ) -> Result<Vec<u8>, Error> {
+        debug!("Downloading bill {document_id} of {customer_code}");
+        self.send_raw(self.client.get(self.url(
+            Service::Invoice,
+            ApiVersion::V1_0,
+            format!("bills/{customer_code}/{document_id}/file").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения статуса оплаты счёта
+    pub async fn get_bill_payment_status(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+    ) -> Result<Data<InvoicePaymentStatusInfo>, Error> {
+        debug!("Fetching payment status of bill {document_id}");
+        self.send::<Data<InvoicePaymentStatusInfo>>(self.client.get(self.url(
+            Service::Invoice,
+            ApiVersion::V1_0,
+            format!("bills/{customer_code}/{document_id}/payment-status").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для создания закрывающего документа (акт, накладная, счёт-фактура, УПД)
+    pub async fn create_closing_document(
+        &self,
+        payload: ClosingDocumentPayload,
+    ) -> Result<Data<InvoiceDocumentCreated>, Error> {
+        debug!("Creating closing document with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<InvoiceDocumentCreated>>(
+            self.client
+                .post(self.url(Service::Invoice, ApiVersion::V1_0, "closing-documents"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для удаления закрывающего документа
+    pub async fn delete_closing_document(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Deleting closing document {document_id} of {customer_code}");
+        self.send::<Data<ResultBody>>(self.client.delete(self.url(
+            Service::Invoice,
+            ApiVersion::V1_0,
+            format!("closing-documents/{customer_code}/{document_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для отправки закрывающего документа на email покупателя
+    pub async fn send_closing_document_to_email(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+        email: impl Into<String>,
+    ) -> Result<Data<ResultBody>, Error> {
+        let payload = InvoiceEmailPayload {
+            email: email.into(),
+        };
+        debug!(
+            "Sending closing document {document_id} to {}",
+            payload.email
+        );
+        self.validate_payload(&payload)?;
+        self.send::<Data<ResultBody>>(
+            self.client
+                .post(self.url(
+                    Service::Invoice,
+                    ApiVersion::V1_0,
+                    format!("closing-documents/{customer_code}/{document_id}/email").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения PDF-файла закрывающего документа
+    pub async fn get_closing_document_file(
+        &self,
+        customer_code: &str,
+        document_id: &str,
+    ) -> Result<Vec<u8>, Error> {
+        debug!("Downloading closing document {document_id} of {customer_code}");
+        self.send_raw(self.client.get(self.url(
+            Service::Invoice,
+            ApiVersion::V1_0,
+            format!("closing-documents/{customer_code}/{document_id}/file").as_str(),
+        )))
+        .await
+    }
+}
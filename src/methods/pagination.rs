@@ -0,0 +1,122 @@
+use crate::{
+    ApiVersion, Balance, BalancePageData, Client, Customer, CustomerPageData, Error, PageItems,
+    PageOptions, PaginatedResponse, PaymentListQuery, PaymentOperation, Service,
+};
+use futures_util::{Stream, StreamExt, TryStreamExt, future::ready, stream};
+use log::debug;
+use serde::de::DeserializeOwned;
+
+impl Client {
+    /// Поток балансов по всем счетам, страницы обходятся по ссылке `next`
+    ///
+    /// Из `options` используется только `per_page`: следующая страница известна лишь после
+    /// текущей, поэтому страницы запрашиваются по одной.
+    pub fn balances_stream(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Balance, Error>> + '_ {
+        self.follow_next_links::<BalancePageData>(
+            self.url(Service::OpenBanking, ApiVersion::V1_0, "accounts/balances"),
+            options,
+        )
+    }
+
+    /// Поток доступных клиентов, страницы обходятся по ссылке `next`
+    ///
+    /// Из `options` используется только `per_page`, как в [`Self::balances_stream`].
+    pub fn customers_stream(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Customer, Error>> + '_ {
+        self.follow_next_links::<CustomerPageData>(
+            self.url(Service::OpenBanking, ApiVersion::V1_0, "customers"),
+            options,
+        )
+    }
+
+    /// Поток операций эквайринга
+    ///
+    /// Первая страница даёт `totalPages`, остальные запрашиваются по номеру, до
+    /// `options.concurrency` одновременно. Порядок операций сохраняется.
+    pub fn payment_operations_stream(
+        &self,
+        query: PaymentListQuery,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<PaymentOperation, Error>> + '_ {
+        let first = query.page.unwrap_or(1);
+        let per_page = options.per_page.or(query.per_page);
+        let fetch = move |page: u32| {
+            let query = PaymentListQuery {
+                page: Some(page),
+                per_page,
+                ..query.clone()
+            };
+            self.payment_operation_list(query)
+        };
+
+        stream::once(fetch(first))
+            .map_ok(move |head| {
+                let total = u32::try_from(head.meta.total_pages).unwrap_or(u32::MAX);
+                debug!("Streaming payment operations, {total} page(s) in total");
+                let rest = stream::iter(first.saturating_add(1)..=total)
+                    .map(fetch.clone())
+                    .buffered(options.concurrency.max(1));
+                stream::once(ready(Ok(head))).chain(rest)
+            })
+            .try_flatten()
+            .map_ok(page_items)
+            .try_flatten()
+    }
+
+    /// Ссылка `next` на другой хост отклоняется: запрос ушёл бы туда с токеном доступа.
+    fn follow_next_links<P>(
+        &self,
+        url: String,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<P::Item, Error>> + '_
+    where
+        P: PageItems + DeserializeOwned + 'static,
+    {
+        let first = match options.per_page {
+            Some(per_page) => format!("{url}?perPage={per_page}"),
+            None => url,
+        };
+        stream::try_unfold(Some(first), move |url| async move {
+            let Some(url) = url else {
+                return Ok::<_, Error>(None);
+            };
+            debug!("Fetching page {url}");
+            let page = self
+                .send::<PaginatedResponse<P>>(self.client.get(&url))
+                .await?;
+            let next = match page.links.next.as_deref().filter(|next| !next.is_empty()) {
+                Some(next) => Some(self.next_page_url(&url, next)?),
+                None => None,
+            }
+            .filter(|next| *next != url);
+
+            Ok(Some((page_items(page), next)))
+        })
+        .try_flatten()
+    }
+
+    fn next_page_url(&self, current: &str, next: &str) -> Result<String, Error> {
+        let foreign = || Error::ForeignNextLink(next.to_string());
+        let next_url = reqwest::Url::parse(current)
+            .and_then(|current| current.join(next))
+            .map_err(|_| foreign())?;
+        let base =
+            reqwest::Url::parse(self.base_url()).map_err(|e| Error::Config(e.to_string()))?;
+        if next_url.origin() != base.origin() {
+            return Err(foreign());
+        }
+
+        Ok(next_url.into())
+    }
+}
+
+fn page_items<P: PageItems>(
+    page: PaginatedResponse<P>,
+) -> impl Stream<Item = Result<P::Item, Error>> {
+    stream::iter(page.data.into_items().into_iter().map(Ok))
+}
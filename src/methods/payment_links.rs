@@ -1,7 +1,8 @@
 use crate::{
-    ApiVersion, Client, CreatePaymentPayload, Data, Error, PaginatedResponse, PayloadWrapper,
-    PaymentListQuery, PaymentOperation, PaymentPageData, PaymentPath, PaymentRegistryQuery, Refund,
-    RefundPayload, RegistryPageData, ResultBody, RetailerPageData, Service,
+    ApiVersion, Client, CreatePaymentPayload, CreatePaymentWithReceiptPayload, Data, Error,
+    IdempotencyKey, PaginatedResponse, PayloadWrapper, PaymentListQuery, PaymentOperation,
+    PaymentPageData, PaymentPath, PaymentRefund, PaymentRegistryQuery, Refund, RefundPayload,
+    RefundSummary, RegistryPageData, ResultBody, RetailerPageData, Service,
 };
 use log::debug;
 
@@ -22,28 +23,112 @@ impl Client {
     /// Метод для создания ссылки на оплату
     ///
     /// Path позволяет генерить чек, если он нужен. Для этого передйте PaymentPath с нужным параметром
+    /// Чек с покупателем и позициями собирается в
+    /// [`create_payment_with_receipt`](Self::create_payment_with_receipt).
+    ///
+    /// Запрос уходит без ключа идемпотентности и не повторяется при сбоях сети. Чтобы
+    /// повтор был безопасен, сохраните ключ и используйте
+    /// [`create_payment_operation_with_key`](Self::create_payment_operation_with_key).
     pub async fn create_payment_operation(
         &self,
         payload: CreatePaymentPayload,
         path: PaymentPath,
     ) -> Result<Data<PaymentOperation>, Error> {
-        let path_segment = path.as_str();
         debug!(
-            "Creating payment operation via {path_segment} with payload: {:?}",
+            "Creating payment operation via {} with payload: {:?}",
+            path.as_str(),
             payload
         );
+        self.send(self.create_payment_request(payload, path)?).await
+    }
+
+    /// Метод для создания ссылки на оплату с ключом идемпотентности
+    ///
+    /// Запрос с ключом повторяется при таймаутах и `5xx`.
+    pub async fn create_payment_operation_with_key(
+        &self,
+        payload: CreatePaymentPayload,
+        path: PaymentPath,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<PaymentOperation>, Error> {
+        let key = key.into();
+        debug!(
+            "Creating payment operation via {} with key {key} and payload: {:?}",
+            path.as_str(),
+            payload
+        );
+        self.send_idempotent(self.create_payment_request(payload, path)?, &key)
+            .await
+    }
+
+    fn create_payment_request(
+        &self,
+        payload: CreatePaymentPayload,
+        path: PaymentPath,
+    ) -> Result<reqwest::RequestBuilder, Error> {
         if payload.customer_code.is_none() {
-            return Err(Error::Api(String::from(
+            return Err(Error::Config(String::from(
                 "Нет customer_code. Используйте resolve_customer_code в вашем коде",
             )));
         }
-        self.send::<Data<PaymentOperation>>(
-            self.client
-                .post(self.url(Service::Acquiring, ApiVersion::V1_0, path_segment))
-                .json(&PayloadWrapper::wrap(payload)),
-        )
-        .await
+        self.validate_payload(&payload)?;
+        Ok(self
+            .client
+            .post(self.url(Service::Acquiring, ApiVersion::V1_0, path.as_str()))
+            .json(&PayloadWrapper::wrap(payload)))
     }
+
+    /// Метод для создания ссылки на оплату с чеком
+    ///
+    /// Перед отправкой payload проверяется: при ошибке вернётся [`Error::Validation`],
+    /// запрос в API не уйдёт. Запрос уходит без ключа идемпотентности и не повторяется.
+    pub async fn create_payment_with_receipt(
+        &self,
+        payload: CreatePaymentWithReceiptPayload,
+    ) -> Result<Data<PaymentOperation>, Error> {
+        debug!(
+            "Creating payment operation with receipt and payload: {:?}",
+            payload
+        );
+        self.send(self.create_payment_with_receipt_request(payload)?)
+            .await
+    }
+
+    /// Метод для создания ссылки на оплату с чеком с ключом идемпотентности
+    pub async fn create_payment_with_receipt_with_key(
+        &self,
+        payload: CreatePaymentWithReceiptPayload,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<PaymentOperation>, Error> {
+        let key = key.into();
+        debug!(
+            "Creating payment operation with receipt with key {key} and payload: {:?}",
+            payload
+        );
+        self.send_idempotent(self.create_payment_with_receipt_request(payload)?, &key)
+            .await
+    }
+
+    fn create_payment_with_receipt_request(
+        &self,
+        payload: CreatePaymentWithReceiptPayload,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        if payload.base.customer_code.is_none() {
+            return Err(Error::Config(String::from(
+                "Нет customer_code. Используйте resolve_customer_code в вашем коде",
+            )));
+        }
+        self.validate_payload(&payload)?;
+        Ok(self
+            .client
+            .post(self.url(
+                Service::Acquiring,
+                ApiVersion::V1_0,
+                PaymentPath::WithReceipt.as_str(),
+            ))
+            .json(&PayloadWrapper::wrap(payload)))
+    }
+
     pub async fn payment_operation_info(
         &self,
         operation_id: impl Into<String>,
@@ -59,16 +144,38 @@ impl Client {
     }
 
     /// Метод для списания средств при двухэтапной оплате
+    ///
+    /// Запрос уходит без ключа идемпотентности и не повторяется.
     pub async fn capture_payment(&self, operation_id: &str) -> Result<Data<ResultBody>, Error> {
         debug!("Capturing payment for operation {operation_id}");
-        self.send::<Data<ResultBody>>(self.client.post(self.url(
+        self.send(self.capture_payment_request(operation_id)).await
+    }
+
+    /// Метод для списания средств с ключом идемпотентности
+    pub async fn capture_payment_with_key(
+        &self,
+        operation_id: &str,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<ResultBody>, Error> {
+        let key = key.into();
+        debug!("Capturing payment for operation {operation_id} with key {key}");
+        self.send_idempotent(self.capture_payment_request(operation_id), &key)
+            .await
+    }
+
+    fn capture_payment_request(&self, operation_id: &str) -> reqwest::RequestBuilder {
+        self.client.post(self.url(
             Service::Acquiring,
             ApiVersion::V1_0,
             format!("payments/{operation_id}/capture").as_str(),
-        )))
-        .await
+        ))
     }
 
+    /// Метод для возврата платежа
+    ///
+    /// Запрос уходит без ключа идемпотентности и не повторяется: при сбое сети неизвестно,
+    /// прошёл ли возврат. Для безопасного повтора используйте
+    /// [`refund_payment_operation_with_key`](Self::refund_payment_operation_with_key).
     pub async fn refund_payment_operation(
         &self,
         operation_id: impl Into<String>,
@@ -79,16 +186,117 @@ impl Client {
             "Initiating refund for operation {operation_id} with payload: {:?}",
             payload
         );
-        self.send(
-            self.client
-                .post(self.url(
-                    Service::Acquiring,
-                    ApiVersion::V1_0,
-                    format!("payments/{0}/refund", operation_id).as_str(),
-                ))
-                .json(&PayloadWrapper::wrap(payload)),
-        )
-        .await
+        self.send(self.refund_request(&operation_id, payload)?)
+            .await
+    }
+
+    /// Метод для возврата платежа с ключом идемпотентности
+    ///
+    /// Повтор с тем же ключом не создаст второй возврат.
+    pub async fn refund_payment_operation_with_key(
+        &self,
+        operation_id: impl Into<String>,
+        payload: RefundPayload,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<Refund>, Error> {
+        let operation_id = operation_id.into();
+        let key = key.into();
+        debug!(
+            "Initiating refund for operation {operation_id} with key {key} and payload: {:?}",
+            payload
+        );
+        self.send_idempotent(self.refund_request(&operation_id, payload)?, &key)
+            .await
+    }
+
+    fn refund_request(
+        &self,
+        operation_id: &str,
+        payload: RefundPayload,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        self.validate_payload(&payload)?;
+        Ok(self
+            .client
+            .post(self.url(
+                Service::Acquiring,
+                ApiVersion::V1_0,
+                format!("payments/{operation_id}/refund").as_str(),
+            ))
+            .json(&PayloadWrapper::wrap(payload)))
+    }
+
+    /// Метод для возврата с проверкой остатка
+    ///
+    /// Перед возвратом запрашивает операцию и не отправляет запрос, если сумма больше
+    /// доступной: вернётся [`Error::RefundExceedsRemaining`].
+    ///
+    /// Проверка рекомендательная: между чтением остатка и возвратом может пройти другой
+    /// возврат по той же операции, поэтому лимит в итоге всё равно проверяет сервер.
+    pub async fn refund_payment_operation_checked(
+        &self,
+        operation_id: impl Into<String>,
+        payload: RefundPayload,
+    ) -> Result<Data<Refund>, Error> {
+        let operation_id = operation_id.into();
+        self.check_refund(&operation_id, &payload).await?;
+        self.refund_payment_operation(operation_id, payload).await
+    }
+
+    /// Метод для возврата с проверкой остатка и ключом идемпотентности
+    pub async fn refund_payment_operation_checked_with_key(
+        &self,
+        operation_id: impl Into<String>,
+        payload: RefundPayload,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<Refund>, Error> {
+        let operation_id = operation_id.into();
+        self.check_refund(&operation_id, &payload).await?;
+        self.refund_payment_operation_with_key(operation_id, payload, key)
+            .await
+    }
+
+    async fn check_refund(&self, operation_id: &str, payload: &RefundPayload) -> Result<(), Error> {
+        let summary = self.refund_summary(operation_id).await?;
+        debug!(
+            "Refunding {} of {} remaining on operation {operation_id}",
+            payload.amount, summary.remaining
+        );
+        summary.check_refund(payload.amount)
+    }
+
+    /// Метод для получения суммы списаний, возвратов и остатка по операции
+    pub async fn refund_summary(
+        &self,
+        operation_id: impl Into<String>,
+    ) -> Result<RefundSummary, Error> {
+        let operation_id = operation_id.into();
+        debug!("Fetching refund summary for {operation_id}");
+        let (page, raw) = self
+            .send_with_raw::<Data<PaymentPageData>>(self.client.get(self.url(
+                Service::Acquiring,
+                ApiVersion::V1_0,
+                format!("payments/{operation_id}").as_str(),
+            )))
+            .await?;
+        page.data
+            .operation
+            .first()
+            .map(RefundSummary::from)
+            .ok_or_else(|| Error::MissingData {
+                path: "Data.Operation".into(),
+                raw,
+            })
+    }
+
+    /// Метод для получения одного возврата по номеру заказа
+    pub async fn payment_refund(
+        &self,
+        operation_id: impl Into<String>,
+        order_id: &str,
+    ) -> Result<Option<PaymentRefund>, Error> {
+        let summary = self.refund_summary(operation_id).await?;
+        debug!("Looking up refund {order_id} on {}", summary.operation_id);
+        Ok(summary.refund(order_id).cloned())
     }
 
     /// Метод для получения реестра платежей по интернет-эквайрингу
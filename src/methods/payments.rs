@@ -1,9 +1,69 @@
-use crate::Client;
+use crate::{
+    ApiVersion, Client, Data, Error, PayloadWrapper, PaymentForSignPageData, PaymentForSignQuery,
+    PaymentOrderPayload, PaymentOrderRequest, PaymentOrderStatusInfo, Service,
+};
+use log::debug;
 
 impl Client {
-    pub async fn create_payment_for_sign() {}
-    pub async fn get_payment_for_sign() {}
-    pub async fn create_payment() {}
-    pub async fn get_payment_status() {}
+    /// Метод для создания черновика платежа на подпись
+    ///
+    /// Платёж появится в интернет-банке, подписать его должен пользователь.
+    pub async fn create_payment_for_sign(
+        &self,
+        payload: PaymentOrderPayload,
+    ) -> Result<Data<PaymentOrderRequest>, Error> {
+        debug!("Creating payment for sign with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<PaymentOrderRequest>>(
+            self.client
+                .post(self.url(Service::Payment, ApiVersion::V1_0, "for-sign"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения списка платежей на подпись
+    pub async fn get_payment_for_sign(
+        &self,
+        query: PaymentForSignQuery,
+    ) -> Result<Data<PaymentForSignPageData>, Error> {
+        debug!("Fetching payments for sign with query: {:?}", query);
+        self.send::<Data<PaymentForSignPageData>>(
+            self.client
+                .get(self.url(Service::Payment, ApiVersion::V1_0, "for-sign"))
+                .query(&query),
+        )
+        .await
+    }
+
+    /// Метод для создания платежа
+    ///
+    /// В ответе приходит `requestId` и ссылка `redirectURL` для подписания платежа.
+    pub async fn create_payment(
+        &self,
+        payload: PaymentOrderPayload,
+    ) -> Result<Data<PaymentOrderRequest>, Error> {
+        debug!("Creating payment order with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<PaymentOrderRequest>>(
+            self.client
+                .post(self.url(Service::Payment, ApiVersion::V1_0, "order"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения статуса платежа по `requestId`
+    pub async fn get_payment_status(
+        &self,
+        request_id: &str,
+    ) -> Result<Data<PaymentOrderStatusInfo>, Error> {
+        debug!("Fetching payment order status for request {request_id}");
+        self.send::<Data<PaymentOrderStatusInfo>>(self.client.get(self.url(
+            Service::Payment,
+            ApiVersion::V1_0,
+            format!("status/{request_id}").as_str(),
+        )))
+        .await
+    }
 }
-// Placeholder module for payments-related methods (not yet implemented).
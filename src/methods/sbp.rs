@@ -0,0 +1,342 @@
+use crate::{
+    ApiVersion, CashboxQrActivation, CashboxQrActivationPayload, CashboxQrCodePayload,
+    CashboxQrPayment, Client, Data, Error, PayloadWrapper, QrCode, QrCodePageData, QrCodePayload,
+    QrCodePaymentPageData, RegisteredQrCode, ResultBody, SbpAccount, SbpAccountPageData,
+    SbpEntityStatus, SbpLegalEntity, SbpLegalEntityPayload, SbpLegalEntityRegistered, SbpMerchant,
+    SbpMerchantPageData, SbpMerchantPayload, SbpMerchantRegistered, SbpRefund, SbpRefundPayload,
+    SbpStatusPayload, Service,
+};
+use log::debug;
+
+impl Client {
+    /// Метод для регистрации QR-кода в СБП
+    ///
+    /// Статический QR-код можно оплачивать многократно, динамический — один раз на заданную сумму.
+    pub async fn register_qr_code(
+        &self,
+        merchant_id: &str,
+        account_id: &str,
+        payload: QrCodePayload,
+    ) -> Result<Data<RegisteredQrCode>, Error> {
+        debug!(
+            "Registering QR code for merchant {merchant_id} with payload: {:?}",
+            payload
+        );
+        self.validate_payload(&payload)?;
+        self.send::<Data<RegisteredQrCode>>(
+            self.client
+                .post(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("qr-code/merchant/{merchant_id}/{account_id}").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения списка QR-кодов юрлица
+    pub async fn get_qr_codes_list(&self, legal_id: &str) -> Result<Data<QrCodePageData>, Error> {
+        debug!("Fetching QR codes for legal entity {legal_id}");
+        self.send::<Data<QrCodePageData>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("qr-code/legal-entity/{legal_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения информации о QR-коде
+    pub async fn get_qr_code(&self, qrc_id: &str) -> Result<Data<QrCode>, Error> {
+        debug!("Fetching QR code {qrc_id}");
+        self.send::<Data<QrCode>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("qr-code/{qrc_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения статусов операций по QR-кодам
+    ///
+    /// `qrc_id` совпадает с полем `qrc_id` в [`AcquiringClaims`](crate::AcquiringClaims).
+    pub async fn get_qr_codes_payment_status(
+        &self,
+        qrc_ids: &[&str],
+    ) -> Result<Data<QrCodePaymentPageData>, Error> {
+        let ids = qrc_ids.join(",");
+        debug!("Fetching payment statuses for QR codes {ids}");
+        self.send::<Data<QrCodePaymentPageData>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("qr-codes/{ids}/payment-status").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для регистрации юрлица в СБП
+    pub async fn register_sbp_legal_entity(
+        &self,
+        payload: SbpLegalEntityPayload,
+    ) -> Result<Data<SbpLegalEntityRegistered>, Error> {
+        debug!("Registering SBP legal entity with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<SbpLegalEntityRegistered>>(
+            self.client
+                .post(self.url(Service::Sbp, ApiVersion::V1_0, "register-sbp-legal-entity"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения информации о юрлице в СБП
+    pub async fn get_sbp_legal_entity(
+        &self,
+        legal_id: &str,
+    ) -> Result<Data<SbpLegalEntity>, Error> {
+        debug!("Fetching SBP legal entity {legal_id}");
+        self.send::<Data<SbpLegalEntity>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("legal-entity/{legal_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для активации или приостановки юрлица в СБП
+    pub async fn set_sbp_legal_entity_status(
+        &self,
+        legal_id: &str,
+        status: SbpEntityStatus,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Setting SBP legal entity {legal_id} status to {:?}", status);
+        self.send::<Data<ResultBody>>(
+            self.client
+                .post(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("legal-entity/{legal_id}").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(SbpStatusPayload { status })),
+        )
+        .await
+    }
+
+    /// Метод для регистрации ТСП
+    pub async fn register_sbp_merchant(
+        &self,
+        legal_id: &str,
+        payload: SbpMerchantPayload,
+    ) -> Result<Data<SbpMerchantRegistered>, Error> {
+        debug!(
+            "Registering SBP merchant for {legal_id} with payload: {:?}",
+            payload
+        );
+        self.validate_payload(&payload)?;
+        self.send::<Data<SbpMerchantRegistered>>(
+            self.client
+                .post(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("merchant/legal-entity/{legal_id}").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения списка ТСП юрлица
+    pub async fn get_sbp_merchants_list(
+        &self,
+        legal_id: &str,
+    ) -> Result<Data<SbpMerchantPageData>, Error> {
+        debug!("Fetching SBP merchants of {legal_id}");
+        self.send::<Data<SbpMerchantPageData>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("merchant/legal-entity/{legal_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения информации о ТСП
+    pub async fn get_sbp_merchant(&self, merchant_id: &str) -> Result<Data<SbpMerchant>, Error> {
+        debug!("Fetching SBP merchant {merchant_id}");
+        self.send::<Data<SbpMerchant>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("merchant/{merchant_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для активации или приостановки ТСП
+    pub async fn set_sbp_merchant_status(
+        &self,
+        merchant_id: &str,
+        status: SbpEntityStatus,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Setting SBP merchant {merchant_id} status to {:?}", status);
+        self.send::<Data<ResultBody>>(
+            self.client
+                .put(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("merchant/{merchant_id}").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(SbpStatusPayload { status })),
+        )
+        .await
+    }
+
+    /// Метод для получения списка счетов юрлица, подключённых к СБП
+    pub async fn get_sbp_accounts_list(
+        &self,
+        legal_id: &str,
+    ) -> Result<Data<SbpAccountPageData>, Error> {
+        debug!("Fetching SBP accounts of {legal_id}");
+        self.send::<Data<SbpAccountPageData>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("account/{legal_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения информации о счёте в СБП
+    pub async fn get_sbp_account(
+        &self,
+        legal_id: &str,
+        account_id: &str,
+    ) -> Result<Data<SbpAccount>, Error> {
+        debug!("Fetching SBP account {account_id} of {legal_id}");
+        self.send::<Data<SbpAccount>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("account/{legal_id}/{account_id}").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для активации или приостановки счёта в СБП
+    pub async fn set_sbp_account_status(
+        &self,
+        legal_id: &str,
+        account_id: &str,
+        status: SbpEntityStatus,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!(
+            "Setting SBP account {account_id} of {legal_id} status to {:?}",
+            status
+        );
+        self.send::<Data<ResultBody>>(
+            self.client
+                .put(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("account/{legal_id}/{account_id}").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(SbpStatusPayload { status })),
+        )
+        .await
+    }
+
+    /// Метод для регистрации кассового QR-кода
+    ///
+    /// Кассовый QR-код печатается один раз, а сумма задаётся при каждой активации.
+    pub async fn register_cashbox_qr_code(
+        &self,
+        payload: CashboxQrCodePayload,
+    ) -> Result<Data<RegisteredQrCode>, Error> {
+        debug!("Registering cashbox QR code with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<RegisteredQrCode>>(
+            self.client
+                .post(self.url(Service::Sbp, ApiVersion::V1_0, "cashbox-qr-code"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для активации кассового QR-кода на сумму
+    pub async fn activate_cashbox_qr_code(
+        &self,
+        qrc_id: &str,
+        payload: CashboxQrActivationPayload,
+    ) -> Result<Data<CashboxQrActivation>, Error> {
+        debug!(
+            "Activating cashbox QR code {qrc_id} with payload: {:?}",
+            payload
+        );
+        self.validate_payload(&payload)?;
+        self.send::<Data<CashboxQrActivation>>(
+            self.client
+                .put(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("cashbox-qr-code/{qrc_id}/activate").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для деактивации кассового QR-кода
+    pub async fn deactivate_cashbox_qr_code(
+        &self,
+        qrc_id: &str,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Deactivating cashbox QR code {qrc_id}");
+        self.send::<Data<ResultBody>>(self.client.put(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("cashbox-qr-code/{qrc_id}/deactivate").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для получения статуса оплаты по активации кассового QR-кода
+    pub async fn get_cashbox_qr_code_payment_status(
+        &self,
+        qrc_id: &str,
+        params_id: &str,
+    ) -> Result<Data<CashboxQrPayment>, Error> {
+        debug!("Fetching payment status of cashbox QR code {qrc_id} activation {params_id}");
+        self.send::<Data<CashboxQrPayment>>(
+            self.client
+                .get(self.url(
+                    Service::Sbp,
+                    ApiVersion::V1_0,
+                    format!("cashbox-qr-code/{qrc_id}/payment-status").as_str(),
+                ))
+                .query(&[("paramsId", params_id)]),
+        )
+        .await
+    }
+
+    /// Метод для возврата платежа СБП
+    pub async fn create_sbp_refund(
+        &self,
+        payload: SbpRefundPayload,
+    ) -> Result<Data<SbpRefund>, Error> {
+        debug!("Creating SBP refund with payload: {:?}", payload);
+        self.validate_payload(&payload)?;
+        self.send::<Data<SbpRefund>>(
+            self.client
+                .post(self.url(Service::Sbp, ApiVersion::V1_0, "refund"))
+                .json(&PayloadWrapper::wrap(payload)),
+        )
+        .await
+    }
+
+    /// Метод для получения статуса возврата СБП
+    pub async fn get_sbp_refund(&self, request_id: &str) -> Result<Data<SbpRefund>, Error> {
+        debug!("Fetching SBP refund {request_id}");
+        self.send::<Data<SbpRefund>>(self.client.get(self.url(
+            Service::Sbp,
+            ApiVersion::V1_0,
+            format!("refund/{request_id}").as_str(),
+        )))
+        .await
+    }
+}
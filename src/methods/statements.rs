@@ -1,5 +1,11 @@
-use crate::{Client, Data, Error, PayloadWrapper, Service, StatementPageData, StatementPayload};
+use crate::{
+    Client, Data, Error, PayloadWrapper, PollOptions, Service, Statement, StatementPageData,
+    StatementPayload, StatementStatus,
+};
+use chrono::NaiveDate;
 use log::debug;
+use std::ops::RangeInclusive;
+use tokio::time::{Instant, sleep, timeout_at};
 
 impl Client {
     /// Метод для получения конкретной выписки
@@ -15,12 +21,8 @@ impl Client {
         statement_id: &str,
     ) -> Result<Data<StatementPageData>, Error> {
         debug!("Fetching statement {statement_id} for account {accound_id}");
-        self.send::<Data<StatementPageData>>(self.client.get(self.url(
-            Service::OpenBanking,
-            crate::ApiVersion::V1_0,
-            format!("accounts/{accound_id}/statements/{statement_id}").as_str(),
-        )))
-        .await
+        self.send::<Data<StatementPageData>>(self.statement_request(accound_id, statement_id))
+            .await
     }
 
     /// Метод для создания выписки по конкретному счёту
@@ -29,12 +31,9 @@ impl Client {
         payload: StatementPayload,
     ) -> Result<Data<StatementPageData>, Error> {
         debug!("Initializing statement with payload: {:?}", payload);
-        self.send::<Data<StatementPageData>>(
-            self.client
-                .post(self.url(Service::OpenBanking, crate::ApiVersion::V1_0, "statements"))
-                .json(&PayloadWrapper::wrap(payload)),
-        )
-        .await
+        self.validate_payload(&payload)?;
+        self.send::<Data<StatementPageData>>(self.init_statement_request(payload))
+            .await
     }
     /// Метод для получения списка доступных выписок
     ///  
@@ -54,4 +53,100 @@ impl Client {
         )))
         .await
     }
+
+    /// Метод для создания выписки и ожидания её готовности
+    ///
+    /// Создаёт выписку через `init_statement` и опрашивает `get_statement`, удваивая паузу
+    /// от `options.interval` до `options.max_interval`. Возвращает готовую выписку с
+    /// транзакциями, [`Error::StatementFailed`] при статусе *Error* и
+    /// [`Error::StatementTimeout`], если выписка не готова за `options.timeout`. Пауза не
+    /// бывает меньше [`PollOptions::MIN_INTERVAL`]. Если в ответе нет выписки или её
+    /// `statementId`, возвращается [`Error::MissingData`] с исходным телом ответа.
+    pub async fn wait_for_statement(
+        &self,
+        account_id: &str,
+        period: RangeInclusive<NaiveDate>,
+        options: PollOptions,
+    ) -> Result<Statement, Error> {
+        let started = Instant::now();
+        let (start_date_time, end_date_time) = period.into_inner();
+        let payload = StatementPayload {
+            account_id: account_id.to_string(),
+            start_date_time,
+            end_date_time,
+        };
+        self.validate_payload(&payload)?;
+        let (page, raw) = self
+            .send_with_raw::<Data<StatementPageData>>(self.init_statement_request(payload))
+            .await?;
+        let mut statement = first_statement(page, &raw)?;
+        let Some(statement_id) = statement.statement_id.clone() else {
+            return Err(Error::MissingData {
+                path: "Data.Statement[0].statementId".into(),
+                raw,
+            });
+        };
+        // Поля `PollOptions` публичные, поэтому нижнюю границу паузы проверяем и здесь.
+        let mut delay = options.interval.max(PollOptions::MIN_INTERVAL);
+        let max_interval = options.max_interval.max(delay);
+
+        loop {
+            debug!("Statement {statement_id} is {:?}", statement.status);
+            match statement.status {
+                StatementStatus::Ready => return Ok(statement),
+                StatementStatus::Error => return Err(Error::StatementFailed { statement_id }),
+                StatementStatus::Created | StatementStatus::Processing => {}
+            }
+            if started.elapsed() + delay > options.timeout {
+                // Ответ мог прийти чуть позже срока: ждали мы всё равно не дольше `timeout`.
+                return Err(Error::StatementTimeout {
+                    statement_id,
+                    waited: started.elapsed().min(options.timeout),
+                });
+            }
+            sleep(delay).await;
+            delay = (delay * 2).min(max_interval);
+            // Запрос тоже ограничен сроком, чтобы ожидание не вышло за `options.timeout`.
+            let Ok(response) = timeout_at(
+                started + options.timeout,
+                self.send_with_raw::<Data<StatementPageData>>(
+                    self.statement_request(account_id, &statement_id),
+                ),
+            )
+            .await
+            else {
+                return Err(Error::StatementTimeout {
+                    statement_id,
+                    waited: options.timeout,
+                });
+            };
+            let (page, raw) = response?;
+            statement = first_statement(page, &raw)?;
+        }
+    }
+
+    fn statement_request(&self, account_id: &str, statement_id: &str) -> reqwest::RequestBuilder {
+        self.client.get(self.url(
+            Service::OpenBanking,
+            crate::ApiVersion::V1_0,
+            format!("accounts/{account_id}/statements/{statement_id}").as_str(),
+        ))
+    }
+
+    fn init_statement_request(&self, payload: StatementPayload) -> reqwest::RequestBuilder {
+        self.client
+            .post(self.url(Service::OpenBanking, crate::ApiVersion::V1_0, "statements"))
+            .json(&PayloadWrapper::wrap(payload))
+    }
+}
+
+fn first_statement(page: Data<StatementPageData>, raw: &str) -> Result<Statement, Error> {
+    page.data
+        .statement
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::MissingData {
+            path: "Data.Statement".into(),
+            raw: raw.to_string(),
+        })
 }
@@ -0,0 +1,177 @@
+use crate::{
+    ApiVersion, ChargeSubscriptionPayload, Client, CreateSubscriptionPayload, Data, Error,
+    IdempotencyKey, PaginatedResponse, PayloadWrapper, ResultBody, Service, Subscription,
+    SubscriptionListQuery, SubscriptionPageData, SubscriptionStatus, SubscriptionStatusBody,
+};
+use log::debug;
+
+impl Client {
+    /// Метод для создания подписки
+    ///
+    /// В ответе ссылка на первый платёж: после него карта покупателя сохраняется для списаний.
+    /// Запрос уходит без ключа идемпотентности и не повторяется.
+    pub async fn create_subscription(
+        &self,
+        payload: CreateSubscriptionPayload,
+    ) -> Result<Data<Subscription>, Error> {
+        debug!("Creating subscription with payload: {:?}", payload);
+        self.send(self.create_subscription_request(payload)?).await
+    }
+
+    /// Метод для создания подписки с ключом идемпотентности
+    pub async fn create_subscription_with_key(
+        &self,
+        payload: CreateSubscriptionPayload,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<Subscription>, Error> {
+        let key = key.into();
+        debug!(
+            "Creating subscription with key {key} and payload: {:?}",
+            payload
+        );
+        self.send_idempotent(self.create_subscription_request(payload)?, &key)
+            .await
+    }
+
+    fn create_subscription_request(
+        &self,
+        payload: CreateSubscriptionPayload,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        if payload.customer_code.is_none() {
+            return Err(Error::Config(String::from(
+                "Нет customer_code. Используйте resolve_customer_code в вашем коде",
+            )));
+        }
+        self.validate_payload(&payload)?;
+        Ok(self
+            .client
+            .post(self.url(Service::Acquiring, ApiVersion::V1_0, "subscriptions"))
+            .json(&PayloadWrapper::wrap(payload)))
+    }
+
+    /// Метод для получения списка подписок
+    pub async fn subscription_list(
+        &self,
+        query: SubscriptionListQuery,
+    ) -> Result<PaginatedResponse<SubscriptionPageData>, Error> {
+        debug!("Fetching subscriptions list with query: {:?}", query);
+        self.send::<PaginatedResponse<SubscriptionPageData>>(
+            self.client
+                .get(self.url(Service::Acquiring, ApiVersion::V1_0, "subscriptions"))
+                .query(&query),
+        )
+        .await
+    }
+
+    /// Метод для получения информации о подписке
+    ///
+    /// API отдаёт подписку списком `Data.Subscription` из одного элемента; метод возвращает
+    /// сам элемент, а пустой список — как [`Error::MissingData`].
+    pub async fn get_subscription(&self, operation_id: &str) -> Result<Data<Subscription>, Error> {
+        debug!("Fetching subscription {operation_id}");
+        let (page, raw) = self
+            .send_with_raw::<Data<SubscriptionPageData>>(self.client.get(self.url(
+                Service::Acquiring,
+                ApiVersion::V1_0,
+                format!("subscriptions/{operation_id}").as_str(),
+            )))
+            .await?;
+        let Some(subscription) = page.data.subscription.into_iter().next() else {
+            return Err(Error::MissingData {
+                path: "Data.Subscription".into(),
+                raw,
+            });
+        };
+        Ok(Data {
+            data: subscription,
+            links: page.links,
+            meta: page.meta,
+        })
+    }
+
+    /// Метод для получения статуса подписки
+    pub async fn get_subscription_status(
+        &self,
+        operation_id: &str,
+    ) -> Result<Data<SubscriptionStatusBody>, Error> {
+        debug!("Fetching status of subscription {operation_id}");
+        self.send::<Data<SubscriptionStatusBody>>(self.client.get(self.url(
+            Service::Acquiring,
+            ApiVersion::V1_0,
+            format!("subscriptions/{operation_id}/status").as_str(),
+        )))
+        .await
+    }
+
+    /// Метод для изменения статуса подписки, например отмены
+    pub async fn set_subscription_status(
+        &self,
+        operation_id: &str,
+        status: SubscriptionStatus,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!("Setting status of subscription {operation_id} to {status:?}");
+        self.send::<Data<ResultBody>>(
+            self.client
+                .post(self.url(
+                    Service::Acquiring,
+                    ApiVersion::V1_0,
+                    format!("subscriptions/{operation_id}/status").as_str(),
+                ))
+                .json(&PayloadWrapper::wrap(SubscriptionStatusBody { status })),
+        )
+        .await
+    }
+
+    /// Метод для списания с сохранённой карты по подписке
+    ///
+    /// Запрос уходит без ключа идемпотентности и не повторяется.
+    pub async fn charge_subscription(
+        &self,
+        operation_id: &str,
+        payload: ChargeSubscriptionPayload,
+    ) -> Result<Data<ResultBody>, Error> {
+        debug!(
+            "Charging subscription {operation_id} with payload: {:?}",
+            payload
+        );
+        self.send(self.charge_subscription_request(operation_id, payload)?)
+            .await
+    }
+
+    /// Метод для списания по подписке с ключом идемпотентности
+    ///
+    /// Повтор с тем же ключом не спишет деньги второй раз.
+    pub async fn charge_subscription_with_key(
+        &self,
+        operation_id: &str,
+        payload: ChargeSubscriptionPayload,
+        key: impl Into<IdempotencyKey>,
+    ) -> Result<Data<ResultBody>, Error> {
+        let key = key.into();
+        debug!(
+            "Charging subscription {operation_id} with key {key} and payload: {:?}",
+            payload
+        );
+        self.send_idempotent(
+            self.charge_subscription_request(operation_id, payload)?,
+            &key,
+        )
+        .await
+    }
+
+    fn charge_subscription_request(
+        &self,
+        operation_id: &str,
+        payload: ChargeSubscriptionPayload,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        self.validate_payload(&payload)?;
+        Ok(self
+            .client
+            .post(self.url(
+                Service::Acquiring,
+                ApiVersion::V1_0,
+                format!("subscriptions/{operation_id}/charge").as_str(),
+            ))
+            .json(&PayloadWrapper::wrap(payload)))
+    }
+}
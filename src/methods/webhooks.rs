@@ -15,6 +15,7 @@ impl Client {
             payload
         );
 
+        self.validate_payload(&payload)?;
         self.send::<Data<Webhook>>(
             self.client
                 .put(self.url(Service::Webhook, crate::ApiVersion::V1_0, id))
@@ -34,6 +35,7 @@ impl Client {
             "Editing webhook for client_id {id} with payload: {:?}",
             payload
         );
+        self.validate_payload(&payload)?;
         self.send::<Data<Webhook>>(
             self.client
                 .post(self.url(Service::Webhook, crate::ApiVersion::V1_0, id))
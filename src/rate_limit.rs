@@ -0,0 +1,121 @@
+use crate::Service;
+use log::debug;
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+
+/// RU: Квота: `burst` запросов подряд, затем `requests` за `period`.
+/// EN: Quota: `burst` requests at once, then `requests` per `period`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// RU: Запросов за период. EN: Requests per period.
+    pub requests: u32,
+    /// RU: Период. EN: Period.
+    pub period: Duration,
+    /// RU: Размер «корзины». EN: Bucket capacity.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// RU: `requests` запросов за `period`. EN: `requests` per `period`.
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        Self {
+            requests,
+            period,
+            burst: requests,
+        }
+    }
+
+    /// RU: `requests` запросов в секунду. EN: `requests` per second.
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    fn tokens_per_sec(&self) -> f64 {
+        f64::from(self.requests) / self.period.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            tokens: f64::from(limit.burst),
+            updated: Instant::now(),
+        }
+    }
+
+    /// Берёт токен (баланс может уйти в минус) и возвращает, сколько ждать.
+    fn reserve(&mut self) -> Duration {
+        let now = Instant::now();
+        let rate = self.limit.tokens_per_sec();
+        let refill = now.duration_since(self.updated).as_secs_f64() * rate;
+        self.tokens = (self.tokens + refill).min(f64::from(self.limit.burst)) - 1.0;
+        self.updated = now;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / rate)
+        }
+    }
+}
+
+/// RU: Ограничитель частоты запросов (token bucket) по сервисам.
+/// EN: Per-service token bucket rate limiter.
+///
+/// Клиент ждёт свободного токена вместо того, чтобы ловить `429`. Клоны [`Client`](crate::Client)
+/// делят один ограничитель; передайте `Arc<RateLimiter>` в несколько клиентов, чтобы
+/// разделить квоту и между ними.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: HashMap<Service, Mutex<Bucket>>,
+    explicit: Vec<Service>,
+}
+
+impl RateLimiter {
+    /// RU: Ограничитель без квот. EN: Limiter without quotas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// RU: Квота для всех сервисов без собственной. EN: Quota for services without their own.
+    pub fn default_limit(mut self, limit: RateLimit) -> Self {
+        for service in Service::ALL {
+            if !self.explicit.contains(&service) {
+                self.buckets.insert(service, Mutex::new(Bucket::new(limit)));
+            }
+        }
+        self
+    }
+
+    /// RU: Квота для сервиса. EN: Quota for a service.
+    pub fn limit(mut self, service: Service, limit: RateLimit) -> Self {
+        self.explicit.push(service);
+        self.buckets.insert(service, Mutex::new(Bucket::new(limit)));
+        self
+    }
+
+    /// RU: Дождаться разрешения на запрос к сервису. EN: Wait until a request may be sent.
+    pub async fn acquire(&self, service: Service) {
+        let Some(bucket) = self.buckets.get(&service) else {
+            return;
+        };
+        let wait = bucket.lock().await.reserve();
+        if !wait.is_zero() {
+            debug!("Rate limit for {service:?} reached, waiting {wait:?}");
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
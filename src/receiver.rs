@@ -0,0 +1,71 @@
+use crate::{Client, WebhookEvent};
+use axum::{Router, extract::State, http::StatusCode, routing::post};
+use jsonwebtoken::errors::ErrorKind;
+use log::{debug, warn};
+use std::{fmt, future::Future, sync::Arc};
+
+type Callback<E> = Arc<
+    dyn Fn(WebhookEvent) -> std::pin::Pin<Box<dyn Future<Output = Result<(), E>> + Send>>
+        + Send
+        + Sync,
+>;
+
+struct Receiver<E> {
+    client: Client,
+    callback: Callback<E>,
+}
+
+/// RU: Роутер axum, принимающий вебхуки Точки. EN: axum router receiving Tochka webhooks.
+///
+/// Тело запроса — JWT, подписанный ключом Точки. Подпись проверяется по кэшу ключей клиента
+/// (после ротации ключа кэш обновляется, как в [`Client::decode_webhook`]), затем событие
+/// передаётся в `callback`. Ответы:
+/// - `200` — колбэк отработал успешно;
+/// - `401` — подпись не сошлась;
+/// - `400` — токен битый или тип вебхука неизвестен;
+/// - `500` — колбэк вернул ошибку (Точка повторит доставку).
+///
+/// Роутер обрабатывает `POST /`, монтируйте его через `Router::nest`.
+pub fn webhook_router<F, Fut, E>(client: Client, callback: F) -> Router
+where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: fmt::Display + 'static,
+{
+    let receiver = Receiver {
+        client,
+        callback: Arc::new(move |event| Box::pin(callback(event))),
+    };
+
+    Router::new()
+        .route("/", post(receive::<E>))
+        .with_state(Arc::new(receiver))
+}
+
+async fn receive<E: fmt::Display>(
+    State(receiver): State<Arc<Receiver<E>>>,
+    body: String,
+) -> StatusCode {
+    let event = match receiver.client.verify_webhook(body.trim()).await {
+        Ok(event) => event,
+        Err(e) => {
+            warn!("Rejected webhook: {e}");
+            return match e.kind() {
+                ErrorKind::InvalidSignature
+                | ErrorKind::InvalidAlgorithm
+                | ErrorKind::InvalidRsaKey(_)
+                | ErrorKind::InvalidKeyFormat => StatusCode::UNAUTHORIZED,
+                _ => StatusCode::BAD_REQUEST,
+            };
+        }
+    };
+    debug!("Received {:?} webhook", event.webhook_type());
+
+    match (receiver.callback)(event).await {
+        Ok(()) => StatusCode::OK,
+        Err(e) => {
+            warn!("Webhook callback failed: {e}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
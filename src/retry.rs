@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use reqwest::{Method, Request, StatusCode, header::HeaderMap};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// RU: Заголовок ключа идемпотентности. EN: Idempotency key header name.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// RU: Политика повторов при временных ошибках (`429`, `5xx`, таймауты, обрывы сети).
+/// EN: Retry policy for transient failures (`429`, `5xx`, timeouts, network errors).
+///
+/// Повторяются только `GET` и запросы с заголовком [`IDEMPOTENCY_KEY_HEADER`] — иначе
+/// повтор `POST` может, например, создать платёж дважды. Пауза растёт экспоненциально
+/// от `base_delay` до `max_delay` со случайным разбросом; `Retry-After` сервера имеет
+/// приоритет, но если он больше `max_delay`, запрос не повторяется.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// RU: Максимум попыток, включая первую. EN: Max attempts including the first one.
+    pub max_attempts: u32,
+    /// RU: Пауза перед первым повтором. EN: Delay before the first retry.
+    pub base_delay: Duration,
+    /// RU: Предельная пауза. EN: Upper bound for a single delay.
+    pub max_delay: Duration,
+    /// RU: Случайный разброс пауз. EN: Randomize delays.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// RU: Без повторов. EN: Never retry.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// RU: Пауза после неудачной попытки `attempt` (с 1). EN: Delay after failed attempt `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        if !self.jitter {
+            return exp;
+        }
+        // «Equal jitter»: половина паузы фиксирована, половина случайна.
+        let half = exp / 2;
+        half + half.mul_f64(random_unit())
+    }
+
+    /// RU: Пауза с учётом `Retry-After`; `None` — не повторять.
+    /// EN: Delay honouring `Retry-After`; `None` means give up.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        match retry_after {
+            Some(wait) if wait > self.max_delay => None,
+            Some(wait) => Some(wait),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// RU: Можно ли повторять запрос. EN: Whether the request is safe to retry.
+    pub fn is_retry_safe(request: &Request) -> bool {
+        request.method() == Method::GET || request.headers().contains_key(IDEMPOTENCY_KEY_HEADER)
+    }
+
+    /// RU: Временный ли статус ответа. EN: Whether a response status is transient.
+    pub fn is_transient(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+}
+
+/// RU: Разобрать `Retry-After` (секунды или HTTP-дата). EN: Parse `Retry-After` (seconds or HTTP date).
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+
+    Some((at - Utc::now()).to_std().unwrap_or_default())
+}
+
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,1149 @@
+use crate::{
+    Account, AccountDetail, AccountStatus, AccountSubType, AcquiringClaims, Amount, Balance,
+    BalanceType, ChargeSubscriptionPayload, Client, ClientBuilder, CreatePaymentPayload,
+    CreatePaymentWithReceiptPayload, CreateSubscriptionPayload, CreditDebitIndicator, Environment,
+    Error, ExternalType, IDEMPOTENCY_KEY_HEADER, Jwk, Money, Order, OrderType, PayloadWrapper,
+    PaymentClaims, PaymentMode, PaymentOperation, PaymentSide, PaymentStatus, Refund,
+    RefundPayload, SbpB2BPaymentClaims, SbpPaymentClaims, Statement, StatementPayload,
+    StatementStatus, Subscription, SubscriptionStatus, SubscriptionStatusBody,
+    TransactionStatement, Webhook, WebhookEvent, WebhookType,
+};
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::{Path, Query, Request, State},
+    http::{StatusCode, Uri},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use chrono::{NaiveDate, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+use uuid::Uuid;
+use validator::Validate;
+
+/// RU: Код клиента в моке. EN: Customer code used by the mock.
+pub const MOCK_CUSTOMER_CODE: &str = "300000092";
+
+/// RU: `client_id` приложения в моке. EN: Application `client_id` used by the mock.
+pub const MOCK_CLIENT_ID: &str = "mock-client";
+
+/// RU: `kid` тестового ключа вебхуков. EN: Key id of the test webhook key.
+pub const MOCK_WEBHOOK_KID: &str = "test";
+
+// Ключ только для тестов: им подписываются вебхуки мока, в проде он не нужен.
+const MOCK_WEBHOOK_KEY: &str = include_str!("webhook_key.pem");
+const MOCK_WEBHOOK_JWK: &str = include_str!("webhook_jwk.json");
+
+/// RU: Публичный ключ, которым проверяются вебхуки мока. EN: Public key of mock webhooks.
+pub fn mock_jwk() -> Jwk {
+    serde_json::from_str(MOCK_WEBHOOK_JWK).expect("embedded test JWK is valid")
+}
+
+/// RU: Подписать вебхук тестовым ключом. EN: Sign webhook claims with the test key.
+///
+/// Токен проходит проверку клиентом, собранным с [`mock_jwk`].
+pub fn sign_mock_webhook<T: Serialize>(claims: &T) -> String {
+    sign_mock_webhook_with_kid(Some(MOCK_WEBHOOK_KID), claims)
+}
+
+/// RU: Подписать вебхук тестовым ключом с другим `kid` или без него.
+/// EN: Sign webhook claims with the test key under another `kid` or none.
+pub fn sign_mock_webhook_with_kid<T: Serialize>(kid: Option<&str>, claims: &T) -> String {
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = kid.map(String::from);
+    let key =
+        EncodingKey::from_rsa_pem(MOCK_WEBHOOK_KEY.as_bytes()).expect("embedded test key is valid");
+    jsonwebtoken::encode(&header, claims, &key).expect("webhook claims serialize to JSON")
+}
+
+/// RU: Внутрипроцессный мок API Точки. EN: In-process mock of the Tochka API.
+///
+/// Поднимает HTTP-сервер на `127.0.0.1` и хранит состояние в памяти: счета, балансы,
+/// выписки, платёжные ссылки и подписки эквайринга, настройку вебхуков и журнал запросов. Клиент из
+/// [`MockServer::client`] ходит в него как в песочницу и проверяет вебхуки по [`mock_jwk`].
+/// Сервер останавливается при drop.
+pub struct MockServer {
+    url: String,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct MockState {
+    url: String,
+    accounts: Vec<Account>,
+    balances: Vec<Balance>,
+    transactions: HashMap<String, Vec<TransactionStatement>>,
+    statements: Vec<MockStatement>,
+    statement_polls: u32,
+    payments: Vec<PaymentOperation>,
+    subscriptions: Vec<Subscription>,
+    webhook: Option<Webhook>,
+    requests: Vec<MockRequest>,
+    failures: VecDeque<StatusCode>,
+    next_id: u128,
+}
+
+/// RU: Запрос, дошедший до мока. EN: Request received by the mock.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// RU: HTTP-метод. EN: HTTP method.
+    pub method: String,
+    /// RU: Путь без query. EN: Path without the query string.
+    pub path: String,
+    /// RU: Заголовок `Idempotency-Key`. EN: `Idempotency-Key` header.
+    pub idempotency_key: Option<String>,
+    /// RU: Тело, если это JSON. EN: Body, if it is JSON.
+    pub body: Option<serde_json::Value>,
+}
+
+struct MockStatement {
+    statement: Statement,
+    polls_left: u32,
+}
+
+type Shared = Arc<Mutex<MockState>>;
+
+impl MockServer {
+    /// RU: Запустить мок на свободном порту. EN: Start the mock on a free port.
+    ///
+    /// # Panics
+    ///
+    /// Если не удалось занять порт на `127.0.0.1`.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server to 127.0.0.1");
+        let url = format!(
+            "http://{}",
+            listener.local_addr().expect("mock server address")
+        );
+        let state = Arc::new(Mutex::new(MockState {
+            url: url.clone(),
+            ..Default::default()
+        }));
+        let app = router(state.clone());
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                warn!("Mock server stopped: {e}");
+            }
+        });
+        debug!("Mock Tochka server listening on {url}");
+
+        Self { url, state, task }
+    }
+
+    /// RU: Базовый URL мока. EN: Mock base URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// RU: Билдер клиента, настроенный на мок. EN: Client builder pointed at the mock.
+    pub fn client_builder(&self) -> ClientBuilder {
+        Client::builder()
+            .environment(Environment::Sandbox)
+            .base_url(&self.url)
+            .customer_code(MOCK_CUSTOMER_CODE)
+            .client_id(MOCK_CLIENT_ID)
+            .jwk(mock_jwk())
+    }
+
+    /// RU: Клиент, настроенный на мок. EN: Client pointed at the mock.
+    pub async fn client(&self) -> Result<Client, Error> {
+        self.client_builder().build().await
+    }
+
+    /// RU: Добавить счёт с остатком. EN: Add an account with a closing balance.
+    pub fn add_account(&self, account_id: &str, balance: Money) -> Account {
+        let now = Utc::now();
+        let account = Account {
+            customer_code: MOCK_CUSTOMER_CODE.into(),
+            account_id: account_id.into(),
+            transit_account: None,
+            status: AccountStatus::Enabled,
+            status_update_date_time: now,
+            currency: balance.currency(),
+            account_type: ExternalType::Business,
+            account_sub_type: AccountSubType::CurrentAccount,
+            registration_date: now.date_naive(),
+            account_details: Some(vec![AccountDetail {
+                identification: account_id.into(),
+                name: "Mock account".into(),
+                scheme_name: "RU.CBR.PAN".into(),
+            }]),
+        };
+        let mut state = self.state();
+        state.accounts.push(account.clone());
+        state.balances.push(closing_balance(account_id, balance));
+        account
+    }
+
+    /// RU: Изменить остаток счёта. EN: Replace the closing balance of an account.
+    pub fn set_balance(&self, account_id: &str, balance: Money) {
+        let mut state = self.state();
+        state.balances.retain(|b| b.account_id != account_id);
+        state.balances.push(closing_balance(account_id, balance));
+    }
+
+    /// RU: Добавить проводку в выписки счёта. EN: Add a transaction to account statements.
+    ///
+    /// В выписку попадают проводки, у которых `documentProcessDate` внутри периода.
+    pub fn add_transaction(&self, account_id: &str, transaction: TransactionStatement) {
+        self.state()
+            .transactions
+            .entry(account_id.into())
+            .or_default()
+            .push(transaction);
+    }
+
+    /// RU: Сколько опросов выписка будет в `Processing`. EN: Polls before a statement is ready.
+    ///
+    /// По умолчанию `0`: выписка готова при первом же запросе.
+    pub fn statement_polls(&self, polls: u32) {
+        self.state().statement_polls = polls;
+    }
+
+    /// RU: Платёжные операции мока. EN: Payment operations stored by the mock.
+    pub fn payments(&self) -> Vec<PaymentOperation> {
+        self.state().payments.clone()
+    }
+
+    /// RU: Платёжная операция по идентификатору. EN: Payment operation by ID.
+    pub fn payment(&self, operation_id: Uuid) -> Option<PaymentOperation> {
+        self.state()
+            .payments
+            .iter()
+            .find(|p| p.operation_id == operation_id)
+            .cloned()
+    }
+
+    /// RU: Подписки мока. EN: Subscriptions stored by the mock.
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        self.state().subscriptions.clone()
+    }
+
+    /// RU: Все запросы к моку по порядку. EN: Every request received, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state().requests.clone()
+    }
+
+    /// RU: Ответить ошибкой на следующие `times` запросов. EN: Fail the next `times` requests.
+    ///
+    /// Запросы попадают в [`requests`](Self::requests), но до обработчиков не доходят
+    /// и состояние не меняют. Так проверяются повторы при `5xx` и `429`.
+    pub fn fail_next(&self, status: reqwest::StatusCode, times: usize) {
+        self.state()
+            .failures
+            .extend(std::iter::repeat_n(status, times));
+    }
+
+    /// RU: Текущая настройка вебхуков. EN: Current webhook configuration.
+    pub fn webhook(&self) -> Option<Webhook> {
+        self.state().webhook.clone()
+    }
+
+    /// RU: Сымитировать оплату покупателем. EN: Simulate the buyer paying.
+    ///
+    /// Меняет статус операции и, если приложение подписано на `acquiringInternetPayment`,
+    /// отправляет подписанный вебхук. Возвращает HTTP-статус доставки.
+    pub async fn complete_payment(
+        &self,
+        operation_id: Uuid,
+        status: PaymentStatus,
+    ) -> Result<Option<reqwest::StatusCode>, Error> {
+        let claims = {
+            let mut state = self.state();
+            let payment = state
+                .payments
+                .iter_mut()
+                .find(|p| p.operation_id == operation_id)
+                .ok_or_else(|| Error::Config(format!("unknown operation {operation_id}")))?;
+            payment.status = status;
+            if status == PaymentStatus::Approved {
+                payment.paid_at = Some(Utc::now().to_rfc3339());
+            }
+            acquiring_claims(payment)
+        };
+        let event = WebhookEvent::AcquiringInternetPayment(claims);
+        if !self.subscribed(WebhookType::AcquiringInternetPayment) {
+            return Ok(None);
+        }
+        self.deliver_webhook(&event).await.map(Some)
+    }
+
+    /// RU: Отправить подписанный вебхук на URL из настройки. EN: Deliver a signed webhook.
+    pub async fn deliver_webhook(
+        &self,
+        event: &WebhookEvent,
+    ) -> Result<reqwest::StatusCode, Error> {
+        let url = self
+            .webhook()
+            .map(|webhook| webhook.url)
+            .ok_or_else(|| Error::Config("no webhook is registered in the mock".into()))?;
+        deliver(&url, event).await
+    }
+
+    fn subscribed(&self, webhook_type: WebhookType) -> bool {
+        self.state()
+            .webhook
+            .as_ref()
+            .is_some_and(|webhook| webhook.webhooks_list.contains(&webhook_type))
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        lock(&self.state)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn lock(state: &Shared) -> MutexGuard<'_, MockState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn router(state: Shared) -> Router {
+    Router::new()
+        .route("/open-banking/v1.0/accounts", get(accounts))
+        .route("/open-banking/v1.0/accounts/balances", get(balances))
+        .route("/open-banking/v1.0/accounts/{*path}", get(account_resource))
+        .route(
+            "/open-banking/v1.0/statements",
+            get(statements).post(init_statement),
+        )
+        .route(
+            "/acquiring/v1.0/payments",
+            get(payments).post(create_payment),
+        )
+        .route(
+            "/acquiring/v1.0/payments_with_receipt",
+            post(create_payment_with_receipt),
+        )
+        .route("/acquiring/v1.0/payments/{operation_id}", get(payment))
+        .route(
+            "/acquiring/v1.0/payments/{operation_id}/capture",
+            post(capture),
+        )
+        .route(
+            "/acquiring/v1.0/payments/{operation_id}/refund",
+            post(refund),
+        )
+        .route(
+            "/webhook/v1.0/{client_id}",
+            get(get_webhook)
+                .put(put_webhook)
+                .post(edit_webhook)
+                .delete(delete_webhook),
+        )
+        .route(
+            "/acquiring/v1.0/subscriptions",
+            get(subscriptions).post(create_subscription),
+        )
+        .route(
+            "/acquiring/v1.0/subscriptions/{operation_id}",
+            get(subscription),
+        )
+        .route(
+            "/acquiring/v1.0/subscriptions/{operation_id}/status",
+            get(subscription_status).post(set_subscription_status),
+        )
+        .route(
+            "/acquiring/v1.0/subscriptions/{operation_id}/charge",
+            post(charge_subscription),
+        )
+        .route("/webhook/v1.0/{client_id}/test_send", post(test_send))
+        .layer(middleware::from_fn_with_state(state.clone(), record))
+        .with_state(state)
+}
+
+// Пишет запрос в журнал и, если задан `fail_next`, отвечает ошибкой вместо обработчика.
+async fn record(State(state): State<Shared>, request: Request, next: Next) -> Response {
+    let (parts, body) = request.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidBody",
+            "request body cannot be read",
+        );
+    };
+    let failure = {
+        let mut state = lock(&state);
+        state.requests.push(MockRequest {
+            method: parts.method.to_string(),
+            path: parts.uri.path().into(),
+            idempotency_key: parts
+                .headers
+                .get(IDEMPOTENCY_KEY_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from),
+            body: serde_json::from_slice(&bytes).ok(),
+        });
+        state.failures.pop_front()
+    };
+    match failure {
+        Some(status) => error(status, "Error.Injected", "failure injected by the mock"),
+        None => {
+            next.run(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        }
+    }
+}
+
+fn data<T: Serialize>(uri: &Uri, data: T) -> Response {
+    Json(json!({
+        "Data": data,
+        "Links": { "self": uri.to_string() },
+        "Meta": { "totalPages": 1 },
+    }))
+    .into_response()
+}
+
+// Тело ошибки в формате Точки, чтобы его разобрал `ApiError`.
+fn error(status: StatusCode, code: &str, message: impl Into<String>) -> Response {
+    let message = message.into();
+    let body = json!({
+        "code": status.as_str(),
+        "message": message,
+        "Errors": [{ "errorCode": code, "message": message, "url": null }],
+    });
+    (status, Json(body)).into_response()
+}
+
+fn not_found(what: &str, id: &str) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "Error.NotFound",
+        format!("{what} {id} not found"),
+    )
+}
+
+fn closing_balance(account_id: &str, balance: Money) -> Balance {
+    Balance {
+        account_id: account_id.into(),
+        credit_debit_indicator: if balance.is_negative() {
+            CreditDebitIndicator::Debit
+        } else {
+            CreditDebitIndicator::Credit
+        },
+        balance_type: BalanceType::ClosingAvailable,
+        date_time: Utc::now(),
+        amount: Amount {
+            amount: balance,
+            amount_nat: None,
+        },
+    }
+}
+
+async fn accounts(State(state): State<Shared>, uri: Uri) -> Response {
+    data(&uri, json!({ "Account": lock(&state).accounts }))
+}
+
+// `accountId` содержит `/` (`счёт/БИК`), поэтому путь разбирается вручную.
+async fn account_resource(
+    State(state): State<Shared>,
+    Path(path): Path<String>,
+    uri: Uri,
+) -> Response {
+    if let Some((account_id, statement_id)) = path.split_once("/statements/") {
+        return statement(&state, account_id, statement_id, &uri);
+    }
+    match path.strip_suffix("/balances") {
+        Some(account_id) => account_balance(&state, account_id, &uri),
+        None => account(&state, &path, &uri),
+    }
+}
+
+fn account(state: &Shared, account_id: &str, uri: &Uri) -> Response {
+    match lock(state)
+        .accounts
+        .iter()
+        .find(|a| a.account_id == account_id)
+    {
+        Some(account) => data(uri, account),
+        None => not_found("account", account_id),
+    }
+}
+
+async fn balances(State(state): State<Shared>, uri: Uri) -> Response {
+    data(&uri, json!({ "Balance": lock(&state).balances }))
+}
+
+fn account_balance(state: &Shared, account_id: &str, uri: &Uri) -> Response {
+    match lock(state)
+        .balances
+        .iter()
+        .find(|b| b.account_id == account_id)
+    {
+        Some(balance) => data(uri, balance),
+        None => not_found("account", account_id),
+    }
+}
+
+async fn init_statement(
+    State(state): State<Shared>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<StatementPayload>>,
+) -> Response {
+    let payload = payload.data;
+    let mut state = lock(&state);
+    if !state
+        .accounts
+        .iter()
+        .any(|a| a.account_id == payload.account_id)
+    {
+        return not_found("account", &payload.account_id);
+    }
+    if payload.start_date_time > payload.end_date_time {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidPeriod",
+            "startDateTime is after endDateTime",
+        );
+    }
+    state.next_id += 1;
+    let statement = Statement {
+        account_id: payload.account_id,
+        statement_id: Some(format!("statement-{}", state.next_id)),
+        status: StatementStatus::Created,
+        start_date_time: payload.start_date_time,
+        end_date_time: payload.end_date_time,
+        creation_date_time: Utc::now(),
+        start_date_balance: None,
+        end_date_balance: None,
+        transaction: None,
+    };
+    let polls_left = state.statement_polls;
+    let body = json!({ "Statement": [&statement] });
+    state.statements.push(MockStatement {
+        statement,
+        polls_left,
+    });
+    data(&uri, body)
+}
+
+async fn statements(State(state): State<Shared>, uri: Uri) -> Response {
+    let state = lock(&state);
+    let statements: Vec<&Statement> = state.statements.iter().map(|s| &s.statement).collect();
+    data(&uri, json!({ "Statement": statements }))
+}
+
+fn statement(state: &Shared, account_id: &str, statement_id: &str, uri: &Uri) -> Response {
+    let mut state = lock(state);
+    let state = &mut *state;
+    let Some(mock) = state.statements.iter_mut().find(|s| {
+        s.statement.account_id == account_id
+            && s.statement.statement_id.as_deref() == Some(statement_id)
+    }) else {
+        return not_found("statement", statement_id);
+    };
+
+    if mock.polls_left > 0 {
+        mock.polls_left -= 1;
+        mock.statement.status = StatementStatus::Processing;
+    } else if mock.statement.status != StatementStatus::Ready {
+        let closing = state
+            .balances
+            .iter()
+            .find(|b| b.account_id == account_id)
+            .map(|b| b.amount.amount)
+            .unwrap_or_default();
+        fill_statement(
+            &mut mock.statement,
+            state.transactions.get(account_id),
+            closing,
+        );
+    }
+    data(uri, json!({ "Statement": [&mock.statement] }))
+}
+
+// Конечный остаток — текущий остаток счёта, начальный восстанавливается по проводкам периода.
+fn fill_statement(
+    statement: &mut Statement,
+    transactions: Option<&Vec<TransactionStatement>>,
+    closing: Money,
+) {
+    let period = statement.start_date_time..=statement.end_date_time;
+    let transactions: Vec<TransactionStatement> = transactions
+        .into_iter()
+        .flatten()
+        .filter(|t| t.document_process_date.is_some_and(|d| period.contains(&d)))
+        .cloned()
+        .collect();
+    let turnover: Money = transactions
+        .iter()
+        .map(|t| match t.credit_debit_indicator {
+            CreditDebitIndicator::Credit => t.subfields.amount.amount,
+            CreditDebitIndicator::Debit => -t.subfields.amount.amount,
+        })
+        .fold(Money::zero(closing.currency()), |acc, amount| {
+            acc.checked_add(amount).unwrap_or(acc)
+        });
+
+    statement.status = StatementStatus::Ready;
+    statement.start_date_balance = closing.checked_sub(turnover);
+    statement.end_date_balance = Some(closing);
+    statement.transaction = Some(transactions);
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaymentQuery {
+    customer_code: Option<String>,
+    from_date: Option<NaiveDate>,
+    to_date: Option<NaiveDate>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+    status: Option<PaymentStatus>,
+}
+
+async fn payments(
+    State(state): State<Shared>,
+    Query(query): Query<PaymentQuery>,
+    uri: Uri,
+) -> Response {
+    let state = lock(&state);
+    let matching: Vec<&PaymentOperation> = state
+        .payments
+        .iter()
+        .filter(|p| {
+            let created = p.created_at.map(|at| at.date_naive());
+            query
+                .customer_code
+                .as_ref()
+                .is_none_or(|code| p.customer_code.as_ref() == Some(code))
+                && query.status.is_none_or(|status| p.status == status)
+                && query.from_date.is_none_or(|from| created >= Some(from))
+                && query
+                    .to_date
+                    .is_none_or(|to| created.is_some_and(|at| at <= to))
+        })
+        .collect();
+
+    let per_page = query.per_page.unwrap_or(1000).max(1) as usize;
+    let page = query.page.unwrap_or(1).max(1) as usize;
+    let total_pages = matching.len().div_ceil(per_page).max(1);
+    let items: Vec<_> = matching
+        .into_iter()
+        .skip((page - 1) * per_page)
+        .take(per_page)
+        .collect();
+
+    let link = |page: usize| format!("{}{}?page={page}&perPage={per_page}", state.url, uri.path());
+    Json(json!({
+        "Data": { "Operation": items },
+        "Links": {
+            "self": link(page),
+            "first": link(1),
+            "prev": (page > 1).then(|| link(page - 1)),
+            "next": (page < total_pages).then(|| link(page + 1)),
+            "last": link(total_pages),
+        },
+        "Meta": { "totalPages": total_pages },
+    }))
+    .into_response()
+}
+
+async fn create_payment(
+    State(state): State<Shared>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<CreatePaymentPayload>>,
+) -> Response {
+    let payload = payload.data;
+    if !payload.amount.is_positive() {
+        return invalid_amount();
+    }
+    let mut state = lock(&state);
+    let operation = open_payment(&mut state, payload).clone();
+    data(&uri, operation)
+}
+
+async fn create_payment_with_receipt(
+    State(state): State<Shared>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<CreatePaymentWithReceiptPayload>>,
+) -> Response {
+    let payload = payload.data;
+    if !payload.base.amount.is_positive() {
+        return invalid_amount();
+    }
+    if payload.validate().is_err() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidReceipt",
+            "receipt does not match the payment",
+        );
+    }
+    let mut state = lock(&state);
+    let operation = open_payment(&mut state, payload.base);
+    operation.tax_system_code = payload.tax_system_code;
+    operation.client = Some(payload.client);
+    operation.items = Some(payload.items);
+    operation.supplier = payload.supplier;
+    let operation = operation.clone();
+    data(&uri, operation)
+}
+
+fn invalid_amount() -> Response {
+    error(
+        StatusCode::BAD_REQUEST,
+        "Error.InvalidAmount",
+        "amount must be positive",
+    )
+}
+
+fn open_payment(state: &mut MockState, payload: CreatePaymentPayload) -> &mut PaymentOperation {
+    state.next_id += 1;
+    let operation_id = Uuid::from_u128(state.next_id);
+    let operation = PaymentOperation {
+        customer_code: payload.customer_code,
+        tax_system_code: None,
+        payment_type: None,
+        payment_id: None,
+        transaction_id: None,
+        created_at: Some(Utc::now()),
+        payment_mode: Some(payload.payment_mode),
+        redirect_url: payload.redirect_url,
+        fail_redirect_url: payload.fail_redirect_url,
+        client: None,
+        items: None,
+        purpose: Some(payload.purpose),
+        amount: payload.amount,
+        status: PaymentStatus::Created,
+        operation_id,
+        payment_link: format!("{}/pay/{operation_id}", state.url),
+        merchant_id: payload.merchant_id,
+        consumer_id: payload.consumer_id.and_then(|id| id.parse().ok()),
+        order: None,
+        supplier: None,
+        pre_authorization: payload.pre_authorization,
+        paid_at: None,
+        payment_link_id: payload.payment_link_id,
+        save_card: payload.save_card,
+        ttl: payload.ttl,
+    };
+    state.payments.push(operation);
+    state.payments.last_mut().expect("payment was just pushed")
+}
+
+async fn payment(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    let state = lock(&state);
+    match find_payment(&state.payments, &operation_id) {
+        Some(index) => data(&uri, json!({ "Operation": [&state.payments[index]] })),
+        None => not_found("operation", &operation_id),
+    }
+}
+
+fn find_payment(payments: &[PaymentOperation], operation_id: &str) -> Option<usize> {
+    let operation_id: Uuid = operation_id.parse().ok()?;
+    payments.iter().position(|p| p.operation_id == operation_id)
+}
+
+async fn capture(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    let mut state = lock(&state);
+    let Some(index) = find_payment(&state.payments, &operation_id) else {
+        return not_found("operation", &operation_id);
+    };
+    let payment = &mut state.payments[index];
+    if payment.status != PaymentStatus::Authorized {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidStatus",
+            format!("operation {operation_id} is not authorized"),
+        );
+    }
+    let amount = payment.amount;
+    payment.status = PaymentStatus::Approved;
+    push_order(payment, OrderType::Approval, amount);
+    data(&uri, json!({ "result": true }))
+}
+
+async fn refund(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<RefundPayload>>,
+) -> Response {
+    let amount = payload.data.amount;
+    let mut state = lock(&state);
+    let Some(index) = find_payment(&state.payments, &operation_id) else {
+        return not_found("operation", &operation_id);
+    };
+    let payment = &mut state.payments[index];
+    if !matches!(
+        payment.status,
+        PaymentStatus::Approved | PaymentStatus::RefundedPartially
+    ) {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidStatus",
+            format!("operation {operation_id} cannot be refunded"),
+        );
+    }
+    let remaining = payment
+        .order
+        .iter()
+        .flatten()
+        .filter(|order| matches!(order.order_type, OrderType::Refund))
+        .try_fold(payment.amount, |remaining, order| {
+            remaining.checked_sub(order.amount)
+        });
+    if !amount.is_positive() || remaining.is_none_or(|remaining| amount > remaining) {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidAmount",
+            format!("refund amount {amount} exceeds the refundable amount"),
+        );
+    }
+
+    let order_id = push_order(payment, OrderType::Refund, amount);
+    payment.status = if remaining == Some(amount) {
+        PaymentStatus::Refunded
+    } else {
+        PaymentStatus::RefundedPartially
+    };
+    let refund = Refund {
+        is_refund: true,
+        operation_id: payment.operation_id,
+        amount,
+        date: Utc::now().date_naive(),
+        order_id,
+    };
+    data(&uri, refund)
+}
+
+fn push_order(payment: &mut PaymentOperation, order_type: OrderType, amount: Money) -> String {
+    let orders = payment.order.get_or_insert_with(Vec::new);
+    let order_id = format!("{}-{}", payment.operation_id, orders.len() + 1);
+    orders.push(Order {
+        order_id: order_id.clone(),
+        order_type,
+        amount,
+        time: Utc::now().to_rfc3339(),
+    });
+    order_id
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionQuery {
+    customer_code: Option<String>,
+}
+
+async fn subscriptions(
+    State(state): State<Shared>,
+    Query(query): Query<SubscriptionQuery>,
+    uri: Uri,
+) -> Response {
+    let state = lock(&state);
+    let matching: Vec<&Subscription> = state
+        .subscriptions
+        .iter()
+        .filter(|s| {
+            query
+                .customer_code
+                .as_ref()
+                .is_none_or(|code| s.customer_code.as_ref() == Some(code))
+        })
+        .collect();
+    data(&uri, json!({ "Subscription": matching }))
+}
+
+async fn create_subscription(
+    State(state): State<Shared>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<CreateSubscriptionPayload>>,
+) -> Response {
+    let payload = payload.data;
+    if !payload.amount.is_positive() {
+        return invalid_amount();
+    }
+    let mut state = lock(&state);
+    state.next_id += 1;
+    let operation_id = Uuid::from_u128(state.next_id);
+    let subscription = Subscription {
+        operation_id,
+        status: SubscriptionStatus::Created,
+        amount: payload.amount,
+        purpose: Some(payload.purpose),
+        payment_link: Some(format!("{}/pay/{operation_id}", state.url)),
+        customer_code: payload.customer_code,
+        consumer_id: payload.consumer_id,
+        merchant_id: payload.merchant_id,
+        recurring: payload.recurring,
+        options: payload.options,
+        created_at: Some(Utc::now()),
+    };
+    state.subscriptions.push(subscription.clone());
+    data(&uri, subscription)
+}
+
+fn find_subscription(subscriptions: &[Subscription], operation_id: &str) -> Option<usize> {
+    let operation_id: Uuid = operation_id.parse().ok()?;
+    subscriptions
+        .iter()
+        .position(|s| s.operation_id == operation_id)
+}
+
+// Подписка приходит списком: на неизвестный идентификатор список пустой.
+async fn subscription(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    let state = lock(&state);
+    let found: Vec<&Subscription> = find_subscription(&state.subscriptions, &operation_id)
+        .map(|index| &state.subscriptions[index])
+        .into_iter()
+        .collect();
+    data(&uri, json!({ "Subscription": found }))
+}
+
+async fn subscription_status(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    let state = lock(&state);
+    match find_subscription(&state.subscriptions, &operation_id) {
+        Some(index) => data(
+            &uri,
+            SubscriptionStatusBody {
+                status: state.subscriptions[index].status,
+            },
+        ),
+        None => not_found("subscription", &operation_id),
+    }
+}
+
+async fn set_subscription_status(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+    Json(body): Json<PayloadWrapper<SubscriptionStatusBody>>,
+) -> Response {
+    let mut state = lock(&state);
+    let Some(index) = find_subscription(&state.subscriptions, &operation_id) else {
+        return not_found("subscription", &operation_id);
+    };
+    state.subscriptions[index].status = body.data.status;
+    data(&uri, json!({ "result": true }))
+}
+
+async fn charge_subscription(
+    State(state): State<Shared>,
+    Path(operation_id): Path<String>,
+    uri: Uri,
+    Json(payload): Json<PayloadWrapper<ChargeSubscriptionPayload>>,
+) -> Response {
+    if !payload.data.amount.is_positive() {
+        return invalid_amount();
+    }
+    let state = lock(&state);
+    let Some(index) = find_subscription(&state.subscriptions, &operation_id) else {
+        return not_found("subscription", &operation_id);
+    };
+    if state.subscriptions[index].status == SubscriptionStatus::Cancelled {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Error.InvalidStatus",
+            format!("subscription {operation_id} is cancelled"),
+        );
+    }
+    data(&uri, json!({ "result": true }))
+}
+
+async fn put_webhook(
+    State(state): State<Shared>,
+    Path(client_id): Path<String>,
+    uri: Uri,
+    Json(webhook): Json<Webhook>,
+) -> Response {
+    if client_id != MOCK_CLIENT_ID {
+        return not_found("client", &client_id);
+    }
+    lock(&state).webhook = Some(webhook.clone());
+    data(&uri, webhook)
+}
+
+async fn edit_webhook(
+    State(state): State<Shared>,
+    Path(client_id): Path<String>,
+    uri: Uri,
+    Json(webhook): Json<Webhook>,
+) -> Response {
+    let mut state = lock(&state);
+    match state.webhook.as_mut() {
+        Some(current) if client_id == MOCK_CLIENT_ID => {
+            *current = webhook.clone();
+            data(&uri, webhook)
+        }
+        _ => not_found("webhook", &client_id),
+    }
+}
+
+async fn get_webhook(
+    State(state): State<Shared>,
+    Path(client_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    match lock(&state).webhook.clone() {
+        Some(webhook) if client_id == MOCK_CLIENT_ID => data(&uri, webhook),
+        _ => not_found("webhook", &client_id),
+    }
+}
+
+async fn delete_webhook(
+    State(state): State<Shared>,
+    Path(client_id): Path<String>,
+    uri: Uri,
+) -> Response {
+    match lock(&state).webhook.take() {
+        Some(_) if client_id == MOCK_CLIENT_ID => data(&uri, json!({ "result": true })),
+        _ => not_found("webhook", &client_id),
+    }
+}
+
+async fn test_send(
+    State(state): State<Shared>,
+    Path(client_id): Path<String>,
+    uri: Uri,
+    Json(webhook_type): Json<WebhookType>,
+) -> Response {
+    let url = match lock(&state).webhook.clone() {
+        Some(webhook) if client_id == MOCK_CLIENT_ID => webhook.url,
+        _ => return not_found("webhook", &client_id),
+    };
+    let delivered = deliver(&url, &sample_event(webhook_type))
+        .await
+        .is_ok_and(|status| status.is_success());
+    data(&uri, json!({ "result": delivered }))
+}
+
+async fn deliver(url: &str, event: &WebhookEvent) -> Result<reqwest::StatusCode, Error> {
+    debug!("Delivering {:?} webhook to {url}", event.webhook_type());
+    let response = reqwest::Client::new()
+        .post(url)
+        .body(sign_mock_webhook(event))
+        .send()
+        .await
+        .map_err(|e| Error::Network(e.to_string()))?;
+    Ok(response.status())
+}
+
+fn acquiring_claims(payment: &PaymentOperation) -> AcquiringClaims {
+    AcquiringClaims {
+        customer_code: payment
+            .customer_code
+            .clone()
+            .unwrap_or_else(|| MOCK_CUSTOMER_CODE.into()),
+        amount: payment.amount,
+        payment_type: payment.payment_type.unwrap_or(PaymentMode::Card),
+        webhook_type: WebhookType::AcquiringInternetPayment,
+        operation_id: payment.operation_id,
+        purpose: payment.purpose.clone().unwrap_or_default(),
+        merchant_id: payment.merchant_id.clone().unwrap_or_default(),
+        status: payment.status,
+        consumer_id: payment.consumer_id,
+        transaction_id: payment.transaction_id,
+        qrc_id: None,
+        payer_name: None,
+    }
+}
+
+fn sample_side(name: &str, account: &str) -> PaymentSide {
+    PaymentSide {
+        bank_code: "044525104".into(),
+        bank_name: Some("ООО \"Банк Точка\"".into()),
+        bank_corr_account: Some("30101810745374525104".into()),
+        account: account.into(),
+        name: name.into(),
+        amount: "100.00".parse().expect("valid amount"),
+        inn: Some("7707083893".into()),
+        kpp: Some("773601001".into()),
+    }
+}
+
+// Примерно то, что присылает `test_send` песочницы.
+fn sample_event(webhook_type: WebhookType) -> WebhookEvent {
+    let amount: Money = "100.00".parse().expect("valid amount");
+    match webhook_type {
+        WebhookType::IncomingPayment | WebhookType::OutgoingPayment => {
+            let claims = PaymentClaims {
+                side_payer: sample_side("ООО \"Плательщик\"", "40702810840000000001"),
+                side_recipient: sample_side("ООО \"Получатель\"", "40702810840000000002"),
+                purpose: "Тестовый платёж".into(),
+                document_number: Some("1".into()),
+                payment_id: "mock-payment-1".into(),
+                date: Utc::now().date_naive(),
+                webhook_type,
+                customer_code: MOCK_CUSTOMER_CODE.into(),
+            };
+            if webhook_type == WebhookType::IncomingPayment {
+                WebhookEvent::IncomingPayment(claims)
+            } else {
+                WebhookEvent::OutgoingPayment(claims)
+            }
+        }
+        WebhookType::IncomingSbpPayment => WebhookEvent::IncomingSbpPayment(SbpPaymentClaims {
+            operation_id: "A00000000000000000000000000000001".into(),
+            qrc_id: "AS1000000000000000000000000000001".into(),
+            amount,
+            payer_mobile_number: Some("+79000000000".into()),
+            payer_name: Some("Иван Иванович И.".into()),
+            brand_name: None,
+            merchant_id: "MA0000000001".into(),
+            purpose: Some("Тестовый платёж".into()),
+            webhook_type,
+            customer_code: MOCK_CUSTOMER_CODE.into(),
+            ref_transaction_id: None,
+        }),
+        WebhookType::AcquiringInternetPayment => {
+            WebhookEvent::AcquiringInternetPayment(AcquiringClaims {
+                customer_code: MOCK_CUSTOMER_CODE.into(),
+                amount,
+                payment_type: PaymentMode::Card,
+                webhook_type,
+                operation_id: Uuid::from_u128(1),
+                purpose: "Тестовый платёж".into(),
+                merchant_id: "200000000000001".into(),
+                status: PaymentStatus::Approved,
+                consumer_id: None,
+                transaction_id: None,
+                qrc_id: None,
+                payer_name: None,
+            })
+        }
+        WebhookType::IncomingSbpB2BPayment => {
+            WebhookEvent::IncomingSbpB2BPayment(SbpB2BPaymentClaims {
+                operation_id: "B00000000000000000000000000000001".into(),
+                qrc_id: "BS1000000000000000000000000000001".into(),
+                amount,
+                purpose: Some("Тестовый платёж".into()),
+                merchant_id: "MB0000000001".into(),
+                webhook_type,
+                customer_code: MOCK_CUSTOMER_CODE.into(),
+                side_payer: Some(sample_side("ООО \"Плательщик\"", "40702810840000000001")),
+            })
+        }
+    }
+}
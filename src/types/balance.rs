@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::Amount;
+use crate::{Amount, PageItems};
 
 /// RU: Модель баланса счёта. EN: Account balance model.
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -44,3 +44,11 @@ pub struct BalancePageData {
     /// RU: Список балансов. EN: Balance collection.
     pub balance: Vec<Balance>,
 }
+
+impl PageItems for BalancePageData {
+    type Item = Balance;
+
+    fn into_items(self) -> Vec<Balance> {
+        self.balance
+    }
+}
@@ -1,5 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Clone, Serialize, Deserialize)]
+
+/// RU: Права, которые выдаются разрешением. EN: Permissions granted by a consent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ExternalConsentTypeEnum {
     #[serde(rename = "ReadAccountsBasic")]
     ReadAccountsBasic,
@@ -40,3 +43,105 @@ pub enum ExternalConsentTypeEnum {
     #[serde(rename = "ManageGuarantee")]
     ManageGuarantee,
 }
+
+/// RU: Статус разрешения. EN: Consent status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsentStatus {
+    /// RU: Ожидает подписания. EN: Awaiting authorisation.
+    AwaitingAuthorisation,
+    /// RU: Подписано. EN: Authorised.
+    Authorised,
+    /// RU: Отклонено. EN: Rejected.
+    Rejected,
+    /// RU: Отозвано. EN: Revoked.
+    Revoked,
+}
+
+/// RU: Разрешение (consent) из API. EN: Consent model from the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Consent {
+    /// RU: Статус. EN: Status.
+    pub status: ConsentStatus,
+    /// RU: Идентификатор разрешения. EN: Consent ID.
+    pub consent_id: String,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: Option<String>,
+    /// RU: Название приложения. EN: Application name.
+    pub application_name: Option<String>,
+    /// RU: Идентификатор приложения. EN: Application client ID.
+    pub client_id: Option<String>,
+    /// RU: Выданные права. EN: Granted permissions.
+    pub permissions: Vec<ExternalConsentTypeEnum>,
+    /// RU: Срок действия. EN: Expiration time.
+    pub expiration_date_time: Option<DateTime<Utc>>,
+    /// RU: Время создания. EN: Creation time.
+    pub creation_date_time: Option<DateTime<Utc>>,
+    /// RU: Время изменения статуса. EN: Status update time.
+    pub status_update_date_time: Option<DateTime<Utc>>,
+    /// RU: Действует ли разрешение. EN: Validity flag.
+    pub is_valid: Option<bool>,
+}
+
+impl Consent {
+    /// RU: Разрешение подписано, не истекло и не помечено недействительным.
+    /// EN: Authorised, not expired and not flagged invalid.
+    pub fn is_active(&self) -> bool {
+        self.status == ConsentStatus::Authorised
+            && self.is_valid != Some(false)
+            && self.expiration_date_time.is_none_or(|exp| exp > Utc::now())
+    }
+
+    /// RU: Права из `required`, которых нет в разрешении. EN: Permissions from `required` not granted.
+    ///
+    /// Для неактивного разрешения возвращаются все запрошенные права.
+    pub fn missing(&self, required: &[ExternalConsentTypeEnum]) -> Vec<ExternalConsentTypeEnum> {
+        required
+            .iter()
+            .filter(|p| !self.is_active() || !self.permissions.contains(p))
+            .copied()
+            .collect()
+    }
+
+    /// RU: Покрывает ли разрешение все права. EN: Whether all permissions are granted.
+    pub fn covers(&self, required: &[ExternalConsentTypeEnum]) -> bool {
+        self.missing(required).is_empty()
+    }
+}
+
+/// RU: Запрос на создание разрешения. EN: Consent creation payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentPayload {
+    /// RU: Запрашиваемые права. EN: Requested permissions.
+    pub permissions: Vec<ExternalConsentTypeEnum>,
+    /// RU: Срок действия. EN: Expiration time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<DateTime<Utc>>,
+}
+
+impl ConsentPayload {
+    /// RU: Запросить набор прав. EN: Request a permission set.
+    pub fn new<I>(permissions: I) -> Self
+    where
+        I: IntoIterator<Item = ExternalConsentTypeEnum>,
+    {
+        Self {
+            permissions: permissions.into_iter().collect(),
+            expiration_date_time: None,
+        }
+    }
+
+    pub fn expiration_date_time(mut self, expires: DateTime<Utc>) -> Self {
+        self.expiration_date_time = Some(expires);
+        self
+    }
+}
+
+/// RU: Страница разрешений. EN: Consent list page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConsentPageData {
+    /// RU: Список разрешений. EN: Consents.
+    pub consent: Vec<Consent>,
+}
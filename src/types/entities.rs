@@ -1,10 +1,10 @@
-use crate::{validate_phone, validate_tax_code};
+use crate::{PageItems, validate_phone, validate_tax_code};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 use validator::Validate;
 
-/// RU: Данные поставщика (для чеков). EN: Supplier information for receipts.
-#[derive(Deserialize, Validate, Serialize, Debug, Clone)]
+/// RU: Данные поставщика (для чеков и счетов). EN: Supplier information for receipts and invoices.
+#[derive(Deserialize, Validate, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Supplier {
     /// RU: Телефон. EN: Phone number.
@@ -84,3 +84,11 @@ pub enum ExternalType {
 pub struct CustomerPageData {
     pub customer: Vec<Customer>,
 }
+
+impl PageItems for CustomerPageData {
+    type Item = Customer;
+
+    fn into_items(self) -> Vec<Customer> {
+        self.customer
+    }
+}
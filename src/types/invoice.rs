@@ -0,0 +1,389 @@
+use crate::{Money, Supplier, UnitCode, VatType, validate_positive_quantity, validate_tax_code};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// RU: Тип покупателя. EN: Counterparty kind.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecondSideType {
+    /// RU: Индивидуальный предприниматель. EN: Sole proprietor.
+    Ip,
+    /// RU: Юридическое лицо. EN: Company.
+    Company,
+}
+
+/// RU: Покупатель (вторая сторона документа). EN: Document counterparty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SecondSide {
+    /// RU: Счёт покупателя. EN: Counterparty account.
+    pub account_id: Option<String>,
+    /// RU: Юридический адрес. EN: Legal address.
+    pub legal_address: Option<String>,
+    /// RU: КПП. EN: KPP.
+    pub kpp: Option<String>,
+    /// RU: Банк покупателя. EN: Counterparty bank name.
+    pub bank_name: Option<String>,
+    /// RU: Корсчёт банка. EN: Bank correspondent account.
+    pub bank_corr_account: Option<String>,
+    /// RU: ИНН. EN: Tax code.
+    #[validate(custom(function = "validate_tax_code"))]
+    pub tax_code: String,
+    /// RU: Тип покупателя. EN: Counterparty kind.
+    #[serde(rename = "type")]
+    pub side_type: SecondSideType,
+    /// RU: Наименование покупателя. EN: Counterparty name.
+    pub second_side_name: Option<String>,
+}
+
+impl SecondSide {
+    /// RU: Покупатель по ИНН. EN: Counterparty by tax code.
+    pub fn new(tax_code: impl Into<String>, side_type: SecondSideType) -> Self {
+        Self {
+            account_id: None,
+            legal_address: None,
+            kpp: None,
+            bank_name: None,
+            bank_corr_account: None,
+            tax_code: tax_code.into(),
+            side_type,
+            second_side_name: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.second_side_name = Some(name.into());
+        self
+    }
+
+    pub fn kpp(mut self, kpp: impl Into<String>) -> Self {
+        self.kpp = Some(kpp.into());
+        self
+    }
+
+    pub fn legal_address(mut self, address: impl Into<String>) -> Self {
+        self.legal_address = Some(address.into());
+        self
+    }
+
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    pub fn bank(mut self, name: impl Into<String>, corr_account: impl Into<String>) -> Self {
+        self.bank_name = Some(name.into());
+        self.bank_corr_account = Some(corr_account.into());
+        self
+    }
+}
+
+/// RU: Позиция документа. EN: Document line item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoicePosition {
+    /// RU: Наименование. EN: Item name.
+    #[validate(length(min = 1, max = 1000))]
+    pub position_name: String,
+    /// RU: Единица измерения. EN: Unit of measure.
+    pub unit_code: UnitCode,
+    /// RU: Ставка НДС. EN: VAT rate.
+    #[serde(with = "super::tax::nds_kind")]
+    pub nds_kind: VatType,
+    /// RU: Цена за единицу. EN: Unit price.
+    pub price: Money,
+    /// RU: Количество. EN: Quantity.
+    #[serde(with = "super::money::number")]
+    #[validate(custom(function = "validate_positive_quantity"))]
+    pub quantity: Decimal,
+    /// RU: Сумма позиции с НДС. EN: Line total including VAT.
+    pub total_amount: Money,
+    /// RU: Сумма НДС позиции. EN: Line VAT amount.
+    pub total_nds: Option<Money>,
+    /// RU: Поставщик (агентская позиция). EN: Supplier of an agency line item.
+    #[serde(rename = "Supplier", skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub supplier: Option<Supplier>,
+}
+
+impl InvoicePosition {
+    /// RU: Позиция с рассчитанными суммой и НДС. EN: Line item with computed totals.
+    pub fn new(
+        position_name: impl Into<String>,
+        unit_code: UnitCode,
+        nds_kind: VatType,
+        price: Money,
+        quantity: Decimal,
+    ) -> Self {
+        let total_amount = price * quantity;
+        let rate = nds_kind.rate();
+        Self {
+            position_name: position_name.into(),
+            unit_code,
+            nds_kind,
+            price,
+            quantity,
+            total_amount,
+            total_nds: Some(total_amount * (rate / (Decimal::ONE_HUNDRED + rate))),
+            supplier: None,
+        }
+    }
+
+    pub fn supplier(mut self, supplier: Supplier) -> Self {
+        self.supplier = Some(supplier);
+        self
+    }
+}
+
+/// RU: Содержимое счёта или закрывающего документа. EN: Bill or closing document body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_invoice_currency"))]
+pub struct InvoiceDocument {
+    /// RU: Номер документа. EN: Document number.
+    #[validate(length(min = 1, max = 40))]
+    pub number: String,
+    /// RU: Дата документа. EN: Document date.
+    pub date: Option<NaiveDate>,
+    /// RU: Итог с НДС. EN: Total including VAT.
+    pub total_amount: Money,
+    /// RU: Итого НДС. EN: Total VAT.
+    pub total_nds: Option<Money>,
+    /// RU: Основание. EN: Basis (contract etc.).
+    pub based_on: Option<String>,
+    /// RU: Комментарий. EN: Comment.
+    pub comment: Option<String>,
+    /// RU: Срок оплаты (только для счёта). EN: Payment due date (bills only).
+    pub payment_expiry_date: Option<NaiveDate>,
+    /// RU: Позиции. EN: Line items.
+    #[serde(rename = "Positions")]
+    #[validate(length(min = 1), nested)]
+    pub positions: Vec<InvoicePosition>,
+}
+
+impl InvoiceDocument {
+    /// RU: Документ из позиций, итоги считаются автоматически. EN: Document with computed totals.
+    ///
+    /// Если позиции в разных валютах, итоги остаются нулевыми и документ не пройдёт валидацию.
+    pub fn new(number: impl Into<String>, positions: Vec<InvoicePosition>) -> Self {
+        let zero = positions
+            .first()
+            .map(|p| Money::zero(p.total_amount.currency()))
+            .unwrap_or_default();
+        let total_amount = positions
+            .iter()
+            .try_fold(zero, |total, p| total.checked_add(p.total_amount))
+            .unwrap_or_default();
+        let total_nds = positions
+            .iter()
+            .filter_map(|p| p.total_nds)
+            .try_fold(zero, Money::checked_add)
+            .unwrap_or_default();
+        Self {
+            number: number.into(),
+            date: None,
+            total_amount,
+            total_nds: Some(total_nds),
+            based_on: None,
+            comment: None,
+            payment_expiry_date: None,
+            positions,
+        }
+    }
+
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    pub fn based_on(mut self, based_on: impl Into<String>) -> Self {
+        self.based_on = Some(based_on.into());
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    pub fn payment_expiry_date(mut self, date: NaiveDate) -> Self {
+        self.payment_expiry_date = Some(date);
+        self
+    }
+}
+
+/// Суммы позиций и итоги документа должны быть в одной валюте.
+fn validate_invoice_currency(document: &InvoiceDocument) -> Result<(), ValidationError> {
+    let currency = document.total_amount.currency();
+    let same_currency = document.positions.iter().all(|p| {
+        p.price.currency() == currency
+            && p.total_amount.currency() == currency
+            && p.total_nds.is_none_or(|nds| nds.currency() == currency)
+    });
+    if same_currency
+        && document
+            .total_nds
+            .is_none_or(|nds| nds.currency() == currency)
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::new("positions_currency"))
+    }
+}
+
+/// RU: Содержимое счёта. EN: Bill content wrapper.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct BillContent {
+    /// RU: Счёт на оплату. EN: Invoice.
+    #[validate(nested)]
+    pub invoice: InvoiceDocument,
+}
+
+/// RU: Запрос на создание счёта на оплату. EN: Bill creation payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BillPayload {
+    /// RU: Счёт продавца. EN: Seller account ID.
+    pub account_id: String,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+    /// RU: Покупатель. EN: Counterparty.
+    #[serde(rename = "SecondSide")]
+    #[validate(nested)]
+    pub second_side: SecondSide,
+    /// RU: Содержимое. EN: Content.
+    #[serde(rename = "Content")]
+    #[validate(nested)]
+    pub content: BillContent,
+}
+
+impl BillPayload {
+    /// RU: Собрать счёт. EN: Build a bill.
+    pub fn new(
+        account_id: impl Into<String>,
+        customer_code: impl Into<String>,
+        second_side: SecondSide,
+        invoice: InvoiceDocument,
+    ) -> Self {
+        Self {
+            account_id: account_id.into(),
+            customer_code: customer_code.into(),
+            second_side,
+            content: BillContent { invoice },
+        }
+    }
+}
+
+/// RU: Вид закрывающего документа. EN: Closing document kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClosingDocumentContent {
+    /// RU: Акт. EN: Act of services.
+    Act(InvoiceDocument),
+    /// RU: Товарная накладная (ТОРГ-12). EN: Packing list.
+    PackingList(InvoiceDocument),
+    /// RU: Счёт-фактура. EN: VAT invoice.
+    Invoicef(InvoiceDocument),
+    /// RU: УПД. EN: Universal transfer document.
+    Upd(InvoiceDocument),
+}
+
+impl ClosingDocumentContent {
+    /// RU: Сам документ независимо от вида. EN: Underlying document regardless of kind.
+    pub fn document(&self) -> &InvoiceDocument {
+        match self {
+            ClosingDocumentContent::Act(doc)
+            | ClosingDocumentContent::PackingList(doc)
+            | ClosingDocumentContent::Invoicef(doc)
+            | ClosingDocumentContent::Upd(doc) => doc,
+        }
+    }
+}
+
+impl Validate for ClosingDocumentContent {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.document().validate()
+    }
+}
+
+/// RU: Запрос на создание закрывающего документа. EN: Closing document creation payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosingDocumentPayload {
+    /// RU: Счёт продавца. EN: Seller account ID.
+    pub account_id: String,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+    /// RU: Счёт, к которому относится документ. EN: Parent bill ID.
+    pub document_id: Option<String>,
+    /// RU: Покупатель. EN: Counterparty.
+    #[serde(rename = "SecondSide")]
+    #[validate(nested)]
+    pub second_side: SecondSide,
+    /// RU: Содержимое. EN: Content.
+    #[serde(rename = "Content")]
+    #[validate(nested)]
+    pub content: ClosingDocumentContent,
+}
+
+impl ClosingDocumentPayload {
+    /// RU: Собрать закрывающий документ. EN: Build a closing document.
+    pub fn new(
+        account_id: impl Into<String>,
+        customer_code: impl Into<String>,
+        second_side: SecondSide,
+        content: ClosingDocumentContent,
+    ) -> Self {
+        Self {
+            account_id: account_id.into(),
+            customer_code: customer_code.into(),
+            document_id: None,
+            second_side,
+            content,
+        }
+    }
+
+    /// RU: Привязать к выставленному счёту. EN: Link to an issued bill.
+    pub fn document_id(mut self, document_id: impl Into<String>) -> Self {
+        self.document_id = Some(document_id.into());
+        self
+    }
+}
+
+/// RU: Ответ на создание документа. EN: Document creation response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDocumentCreated {
+    /// RU: Идентификатор документа. EN: Document ID.
+    pub document_id: String,
+}
+
+/// RU: Запрос на отправку документа по email. EN: Send-to-email payload.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct InvoiceEmailPayload {
+    /// RU: Email покупателя. EN: Recipient email.
+    #[validate(email)]
+    pub email: String,
+}
+
+/// RU: Статус оплаты счёта. EN: Bill payment status.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoicePaymentStatus {
+    /// RU: Ожидает оплаты. EN: Waiting for payment.
+    PaymentWaiting,
+    /// RU: Просрочен. EN: Expired.
+    PaymentExpired,
+    /// RU: Оплачен. EN: Paid.
+    PaymentPaid,
+}
+
+/// RU: Ответ со статусом оплаты. EN: Payment status response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoicePaymentStatusInfo {
+    /// RU: Статус оплаты. EN: Payment status.
+    pub payment_status: InvoicePaymentStatus,
+}
@@ -2,13 +2,18 @@ mod account;
 mod balance;
 mod consent;
 mod entities;
+mod invoice;
+mod money;
 mod payment;
+mod payment_order;
 mod receipt;
 mod refund;
 mod registry;
 mod retailer;
+mod sbp;
 mod service;
 mod statements;
+mod subscription;
 mod tax;
 mod transactions;
 mod version;
@@ -18,13 +23,18 @@ pub use account::*;
 pub use balance::*;
 pub use consent::*;
 pub use entities::*;
+pub use invoice::*;
+pub use money::*;
 pub use payment::*;
+pub use payment_order::*;
 pub use receipt::*;
 pub use refund::*;
 pub use registry::*;
 pub use retailer::*;
+pub use sbp::*;
 pub use service::*;
 pub use statements::*;
+pub use subscription::*;
 pub use tax::*;
 pub use transactions::*;
 pub use version::*;
@@ -80,6 +90,47 @@ pub struct PaginatedResponse<T> {
     pub data: T,
 }
 
+/// RU: Страница, из которой можно достать элементы. EN: Page data that yields its items.
+///
+/// Используется потоками пагинации, например [`Client::customers_stream`](crate::Client::customers_stream).
+pub trait PageItems {
+    /// RU: Тип элемента. EN: Item type.
+    type Item;
+
+    /// RU: Элементы страницы. EN: Items of the page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// RU: Настройки обхода страниц. EN: Pagination stream settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    /// RU: Размер страницы. EN: Page size.
+    pub per_page: Option<u32>,
+    /// RU: Сколько страниц запрашивать одновременно. EN: Pages fetched concurrently.
+    pub concurrency: usize,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self {
+            per_page: None,
+            concurrency: 1,
+        }
+    }
+}
+
+impl PageOptions {
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
 /// RU: Метаданные ответа. EN: Response metadata.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,292 @@
+use codes_iso_4217::CurrencyCode;
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Mul, Neg};
+use std::str::FromStr;
+
+/// RU: Денежная сумма с точностью до копейки. EN: Money amount with kopeck precision.
+///
+/// В JSON пишется числом без потери точности, читается из числа или строки (`"0.33"` в вебхуках).
+/// Валюта в JSON не передаётся: по умолчанию RUB, для сумм рядом с полем
+/// `currency` её проставляет тип-владелец.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    /// RU: Сумма в валюте, округляется до копеек. EN: Amount in currency, rounded to kopecks.
+    pub fn new(amount: Decimal, currency: CurrencyCode) -> Self {
+        let mut amount = amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        amount.rescale(2);
+        Self { amount, currency }
+    }
+
+    /// RU: Сумма в рублях. EN: Amount in rubles.
+    pub fn rub(amount: Decimal) -> Self {
+        Self::new(amount, CurrencyCode::RUB)
+    }
+
+    /// RU: Ноль в валюте. EN: Zero in currency.
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// RU: Сумма из копеек. EN: Amount from minor units.
+    pub fn from_kopecks(kopecks: i64, currency: CurrencyCode) -> Self {
+        Self::new(Decimal::new(kopecks, 2), currency)
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    /// RU: Сумма в копейках. EN: Amount in minor units.
+    ///
+    /// # Panics
+    ///
+    /// Если копейки не помещаются в `i64`; используйте [`Money::checked_kopecks`].
+    pub fn kopecks(&self) -> i64 {
+        self.checked_kopecks()
+            .unwrap_or_else(|| panic!("{self:?} does not fit into i64 kopecks"))
+    }
+
+    /// RU: Сумма в копейках; `None`, если не помещается в `i64`. EN: Minor units, `None` on overflow.
+    pub fn checked_kopecks(&self) -> Option<i64> {
+        self.amount.checked_mul(Decimal::ONE_HUNDRED)?.to_i64()
+    }
+
+    /// RU: Та же сумма в другой валюте. EN: Same amount tagged with another currency.
+    pub fn with_currency(self, currency: CurrencyCode) -> Self {
+        Self { currency, ..self }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.amount > Decimal::ZERO
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount < Decimal::ZERO
+    }
+
+    /// RU: Сложение; `None`, если валюты разные. EN: Addition, `None` on currency mismatch.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        (self.currency == other.currency)
+            .then(|| Self::new(self.amount + other.amount, self.currency))
+    }
+
+    /// RU: Вычитание; `None`, если валюты разные. EN: Subtraction, `None` on currency mismatch.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        (self.currency == other.currency)
+            .then(|| Self::new(self.amount - other.amount, self.currency))
+    }
+}
+
+impl Default for Money {
+    fn default() -> Self {
+        Self::zero(CurrencyCode::RUB)
+    }
+}
+
+impl From<Decimal> for Money {
+    fn from(amount: Decimal) -> Self {
+        Self::rub(amount)
+    }
+}
+
+impl FromStr for Money {
+    type Err = rust_decimal::Error;
+
+    /// RU: Рубли из строки, допускается запятая. EN: Rubles from a string, comma allowed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(&s.trim().replace(',', ".")).map(Self::rub)
+    }
+}
+
+impl TryFrom<f64> for Money {
+    type Error = rust_decimal::Error;
+
+    /// RU: Рубли из `f64` по кратчайшему десятичному представлению. EN: Rubles from `f64`.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        decimal_from_f64(value).map(Self::rub)
+    }
+}
+
+/// Десятичное число из `f64` без двоичного «хвоста».
+pub(crate) fn decimal_from_f64(value: f64) -> Result<Decimal, rust_decimal::Error> {
+    // `Display` у f64 печатает кратчайшую запись, поэтому 0.1 остаётся 0.1, а не 0.1000000000000000055.
+    Decimal::from_str(&value.to_string())
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}", self.amount)
+    }
+}
+
+/// Суммы в разных валютах не сравниваются.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.currency == other.currency).then(|| self.amount.cmp(&other.amount))
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Self::new(-self.amount, self.currency)
+    }
+}
+
+/// RU: Умножение на количество с округлением до копеек. EN: Multiply by a quantity, rounded.
+impl Mul<Decimal> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: Decimal) -> Money {
+        Self::new(self.amount * quantity, self.currency)
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        number::serialize(&self.amount, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        number::deserialize(deserializer).map(Self::rub)
+    }
+}
+
+/// Десятичные числа JSON-числом без потери точности: `#[serde(with = "super::money::number")]`.
+///
+/// Число уходит в JSON через `RawValue` ровно той записью, что у `Decimal`, а при
+/// чтении из текста JSON разбирается его исходная запись, а не `f64`. Фича
+/// `arbitrary_precision` у `serde_json` для этого не нужна. Читается из числа или строки.
+pub(crate) mod number {
+    use super::{Decimal, de, decimal_from_f64, ser};
+    use serde::{Deserializer, Serialize, Serializer};
+    use serde_json::value::RawValue;
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Служебное имя, по которому `serde_json` отдаёт исходный текст значения.
+    const RAW_TOKEN: &str = "$serde_json::private::RawValue";
+    /// Служебный ключ числа, если `arbitrary_precision` включил другой крейт.
+    const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        RawValue::from_string(value.normalize().to_string())
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_newtype_struct(RAW_TOKEN, DecimalVisitor)
+    }
+
+    struct DecimalVisitor;
+
+    impl<'de> de::Visitor<'de> for DecimalVisitor {
+        type Value = Decimal;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a decimal number or a decimal string")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+            Ok(Decimal::from(value))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+            Ok(Decimal::from(value))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Decimal, E> {
+            decimal_from_f64(value).map_err(E::custom)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Decimal, E> {
+            let value = value.trim().replace(',', ".");
+            Decimal::from_str(&value)
+                .or_else(|_| Decimal::from_scientific(&value))
+                .map_err(E::custom)
+        }
+
+        // Не-JSON десериализаторы и буфер `flatten` отдают значение как есть.
+        fn visit_newtype_struct<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Decimal, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+
+        // Так `serde_json` отдаёт исходный текст значения или число с `arbitrary_precision`.
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Decimal, A::Error> {
+            match map.next_entry::<String, String>()? {
+                Some((key, raw)) if key == RAW_TOKEN && raw.starts_with('"') => {
+                    let value: String = serde_json::from_str(&raw).map_err(de::Error::custom)?;
+                    self.visit_str(&value)
+                }
+                Some((key, raw)) if key == RAW_TOKEN || key == NUMBER_TOKEN => self.visit_str(&raw),
+                _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+            }
+        }
+    }
+}
+
+/// Суммы СБП в копейках: `#[serde(with = "super::money::kopecks")]`.
+pub(crate) mod kopecks {
+    use super::{Money, ser};
+    use codes_iso_4217::CurrencyCode;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        let kopecks = money
+            .checked_kopecks()
+            .ok_or_else(|| ser::Error::custom(format!("{money} does not fit into i64 kopecks")))?;
+        serializer.serialize_i64(kopecks)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        i64::deserialize(deserializer)
+            .map(|kopecks| Money::from_kopecks(kopecks, CurrencyCode::RUB))
+    }
+
+    pub mod option {
+        use super::Money;
+        use codes_iso_4217::CurrencyCode;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            money: &Option<Money>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match money {
+                Some(money) => super::serialize(money, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Money>, D::Error> {
+            Option::<i64>::deserialize(deserializer).map(|kopecks| {
+                kopecks.map(|kopecks| Money::from_kopecks(kopecks, CurrencyCode::RUB))
+            })
+        }
+    }
+}
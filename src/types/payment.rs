@@ -64,12 +64,15 @@ pub enum PaymentObject {
     Service,
     Work,
 }
-use crate::{ReceiptClient, ReceiptItem, Supplier, TaxSystemCode};
+use crate::{
+    Money, PageItems, ReceiptClient, ReceiptItem, RefundSummary, Supplier, TaxSystemCode,
+    validate_positive_amount,
+};
 use chrono::{DateTime, NaiveDate, Utc};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 /// RU: Параметры фильтрации списка платежей. EN: Query params for payments list.
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentListQuery {
     /// Уникальный код клиента
@@ -93,7 +96,7 @@ pub struct PaymentListQuery {
 impl PaymentListQuery {
     pub fn new(customer_code: Option<String>) -> Self {
         Self {
-            customer_code: customer_code,
+            customer_code,
             ..Default::default()
         }
     }
@@ -113,6 +116,11 @@ impl PaymentListQuery {
         self
     }
 
+    pub fn per_page(mut self, v: u32) -> Self {
+        self.per_page = Some(v);
+        self
+    }
+
     pub fn status(mut self, v: PaymentStatus) -> Self {
         self.status = Some(v);
         self
@@ -151,7 +159,7 @@ pub struct PaymentOperation {
     /// RU: Назначение платежа. EN: Payment purpose.
     pub purpose: Option<String>,
     /// RU: Сумма платежа. EN: Payment amount.
-    pub amount: f64,
+    pub amount: Money,
     /// RU: Статус платежа. EN: Payment status.
     pub status: PaymentStatus,
     /// RU: Идентификатор операции. EN: Operation ID.
@@ -181,7 +189,14 @@ pub struct PaymentOperation {
     pub ttl: Option<i64>,
 }
 
-#[derive(Serialize, Debug, Deserialize, Clone)]
+impl PaymentOperation {
+    /// RU: Списания, возвраты и остаток по операции. EN: Captured, refunded and remaining amounts.
+    pub fn refund_summary(&self) -> RefundSummary {
+        self.into()
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     /// Идентификатор платежа
@@ -192,13 +207,13 @@ pub struct Order {
     pub order_type: OrderType,
 
     /// Сумма операции
-    pub amount: f64,
+    pub amount: Money,
 
     /// Время операции
     pub time: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, EnumString, Display, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, EnumString, Display, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum OrderType {
     Refund,
@@ -206,10 +221,11 @@ pub enum OrderType {
     Authorized,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePaymentPayload {
-    pub amount: f64,
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
     pub consumer_id: Option<String>,
     pub customer_code: Option<String>,
     pub fail_redirect_url: Option<String>,
@@ -217,6 +233,7 @@ pub struct CreatePaymentPayload {
     pub payment_link_id: Option<String>,
     pub payment_mode: Vec<PaymentMode>,
     pub pre_authorization: Option<bool>,
+    #[validate(length(max = 140))]
     pub purpose: String,
     pub redirect_url: Option<String>,
     pub save_card: Option<bool>,
@@ -224,10 +241,10 @@ pub struct CreatePaymentPayload {
 }
 
 impl CreatePaymentPayload {
-    pub fn new(amount: f64, customer_code: Option<String>, purpose: impl Into<String>) -> Self {
+    pub fn new(amount: Money, customer_code: Option<String>, purpose: impl Into<String>) -> Self {
         Self {
             amount,
-            customer_code: customer_code,
+            customer_code,
             purpose: purpose.into(),
             consumer_id: None,
             fail_redirect_url: None,
@@ -290,8 +307,158 @@ impl CreatePaymentPayload {
     }
 }
 
+/// RU: Запрос на создание платежа с чеком. EN: Payment with receipt creation payload.
+///
+/// Перед отправкой проверяются покупатель, позиции и поставщик, а сумма позиций
+/// (цена × количество) должна совпасть с `amount`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_receipt_total"))]
+pub struct CreatePaymentWithReceiptPayload {
+    /// RU: Поля обычного платежа. EN: Regular payment fields.
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: CreatePaymentPayload,
+    /// RU: Система налогообложения. EN: Tax system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_system_code: Option<TaxSystemCode>,
+    /// RU: Покупатель. EN: Buyer.
+    #[serde(rename = "Client")]
+    #[validate(nested)]
+    pub client: ReceiptClient,
+    /// RU: Позиции чека. EN: Receipt items.
+    #[serde(rename = "Items")]
+    #[validate(length(min = 1), nested)]
+    pub items: Vec<ReceiptItem>,
+    /// RU: Поставщик. EN: Supplier.
+    #[serde(rename = "Supplier", skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub supplier: Option<Supplier>,
+}
+
+impl CreatePaymentWithReceiptPayload {
+    pub fn new(
+        amount: Money,
+        customer_code: Option<String>,
+        purpose: impl Into<String>,
+        client: ReceiptClient,
+    ) -> Self {
+        Self {
+            base: CreatePaymentPayload::new(amount, customer_code, purpose),
+            tax_system_code: None,
+            client,
+            items: Vec::new(),
+            supplier: None,
+        }
+    }
+
+    pub fn item(mut self, item: ReceiptItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = ReceiptItem>,
+    {
+        self.items.extend(items);
+        self
+    }
+
+    pub fn tax_system_code(mut self, code: TaxSystemCode) -> Self {
+        self.tax_system_code = Some(code);
+        self
+    }
+
+    pub fn supplier(mut self, supplier: Supplier) -> Self {
+        self.supplier = Some(supplier);
+        self
+    }
+
+    pub fn fail_redirect_url(mut self, fail_redirect_url: impl Into<String>) -> Self {
+        self.base = self.base.fail_redirect_url(fail_redirect_url);
+        self
+    }
+
+    pub fn redirect_url(mut self, redirect_url: impl Into<String>) -> Self {
+        self.base = self.base.redirect_url(redirect_url);
+        self
+    }
+
+    pub fn save_card(mut self, save_card: bool) -> Self {
+        self.base = self.base.save_card(save_card);
+        self
+    }
+
+    pub fn consumer_id(mut self, id: impl Into<String>) -> Self {
+        self.base = self.base.consumer_id(id);
+        self
+    }
+
+    pub fn merchant_id(mut self, id: impl Into<String>) -> Self {
+        self.base = self.base.merchant_id(id);
+        self
+    }
+
+    pub fn payment_modes<I>(mut self, modes: I) -> Self
+    where
+        I: IntoIterator<Item = PaymentMode>,
+    {
+        self.base = self.base.payment_modes(modes);
+        self
+    }
+
+    pub fn payment_link_id(mut self, id: impl Into<String>) -> Self {
+        self.base = self.base.payment_link_id(id);
+        self
+    }
+
+    pub fn pre_authorization(mut self, pa: bool) -> Self {
+        self.base = self.base.pre_authorization(pa);
+        self
+    }
+
+    pub fn ttl(mut self, value: i64) -> Self {
+        self.base = self.base.ttl(value);
+        self
+    }
+
+    /// RU: Сумма позиций чека; `None`, если валюты разные. EN: Items total, `None` on currency mismatch.
+    pub fn items_total(&self) -> Option<Money> {
+        self.items
+            .iter()
+            .try_fold(Money::zero(self.base.amount.currency()), |total, item| {
+                total.checked_add(item.total())
+            })
+    }
+}
+
+/// Сумма позиций чека должна совпасть с суммой платежа до копейки.
+fn validate_receipt_total(
+    payload: &CreatePaymentWithReceiptPayload,
+) -> Result<(), ValidationError> {
+    match payload.items_total() {
+        Some(total) if total == payload.base.amount => Ok(()),
+        Some(total) => {
+            let mut error = ValidationError::new("items_total");
+            error.add_param("items_total".into(), &total.amount());
+            error.add_param("amount".into(), &payload.base.amount.amount());
+            Err(error)
+        }
+        None => Err(ValidationError::new("items_currency")),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentPageData {
     pub operation: Vec<PaymentOperation>,
 }
+
+impl PageItems for PaymentPageData {
+    type Item = PaymentOperation;
+
+    fn into_items(self) -> Vec<PaymentOperation> {
+        self.operation
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{DateValue, Money, TaxFields, validate_positive_amount, validate_tax_code};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// RU: Платёжное поручение для отправки в банк. EN: Outgoing payment order payload.
+///
+/// Используется и для `for-sign` (черновик на подпись), и для `order` (создание платежа).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentOrderPayload {
+    /// RU: Номер счёта плательщика (20 цифр). EN: Payer account number.
+    #[validate(length(equal = 20))]
+    pub account_code: String,
+    /// RU: БИК банка плательщика. EN: Payer bank BIC.
+    #[validate(length(equal = 9))]
+    pub bank_code: String,
+    /// RU: БИК банка получателя. EN: Counterparty bank BIC.
+    #[validate(length(equal = 9))]
+    pub counterparty_bank_bic: String,
+    /// RU: Счёт получателя. EN: Counterparty account number.
+    #[validate(length(equal = 20))]
+    pub counterparty_account_number: String,
+    /// RU: ИНН получателя. EN: Counterparty INN.
+    #[serde(rename = "counterpartyINN")]
+    #[validate(custom(function = "validate_tax_code"))]
+    pub counterparty_inn: Option<String>,
+    /// RU: КПП получателя. EN: Counterparty KPP.
+    #[serde(rename = "counterpartyKPP")]
+    #[validate(length(equal = 9))]
+    pub counterparty_kpp: Option<String>,
+    /// RU: Наименование получателя. EN: Counterparty name.
+    #[validate(length(min = 1, max = 160))]
+    pub counterparty_name: String,
+    /// RU: Корсчёт банка получателя. EN: Counterparty bank correspondent account.
+    pub counterparty_bank_corr_account: Option<String>,
+    /// RU: Сумма платежа. EN: Payment amount.
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub payment_amount: Money,
+    /// RU: Дата платежа. EN: Payment date.
+    pub payment_date: NaiveDate,
+    /// RU: Номер платёжного поручения. EN: Payment order number.
+    pub payment_number: Option<String>,
+    /// RU: Очерёдность платежа (по умолчанию 5). EN: Payment priority (defaults to 5).
+    pub payment_priority: Option<String>,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    #[validate(length(min = 1, max = 210))]
+    pub payment_purpose: String,
+    /// RU: Код назначения платежа (для физлиц). EN: Payment purpose code.
+    pub code_purpose: Option<String>,
+    /// RU: УИН. EN: Supplier bill identifier (UIN).
+    pub supplier_bill_id: Option<String>,
+    /// RU: Дата документа-основания (поле 109). EN: Tax document date.
+    pub tax_info_document_date: Option<String>,
+    /// RU: Номер документа-основания (поле 108). EN: Tax document number.
+    pub tax_info_document_number: Option<String>,
+    /// RU: КБК (поле 104). EN: Budget classification code.
+    #[serde(rename = "taxInfoKBK")]
+    pub tax_info_kbk: Option<String>,
+    /// RU: ОКТМО (поле 105). EN: OKTMO code.
+    #[serde(rename = "taxInfoOKATO")]
+    pub tax_info_okato: Option<String>,
+    /// RU: Налоговый период (поле 107). EN: Tax period.
+    pub tax_info_period: Option<String>,
+    /// RU: Основание платежа (поле 106). EN: Payment reason code.
+    pub tax_info_reason_code: Option<String>,
+    /// RU: Статус составителя (поле 101). EN: Originator status.
+    pub tax_info_status: Option<String>,
+    /// RU: Код бюджетного платежа (поле 110). EN: Budget payment type code.
+    pub budget_payment_code: Option<String>,
+}
+
+impl PaymentOrderPayload {
+    /// RU: Создать поручение с обязательными реквизитами. EN: Build an order with required fields.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_code: impl Into<String>,
+        bank_code: impl Into<String>,
+        counterparty_bank_bic: impl Into<String>,
+        counterparty_account_number: impl Into<String>,
+        counterparty_name: impl Into<String>,
+        payment_amount: Money,
+        payment_date: NaiveDate,
+        payment_purpose: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_code: account_code.into(),
+            bank_code: bank_code.into(),
+            counterparty_bank_bic: counterparty_bank_bic.into(),
+            counterparty_account_number: counterparty_account_number.into(),
+            counterparty_inn: None,
+            counterparty_kpp: None,
+            counterparty_name: counterparty_name.into(),
+            counterparty_bank_corr_account: None,
+            payment_amount,
+            payment_date,
+            payment_number: None,
+            payment_priority: None,
+            payment_purpose: payment_purpose.into(),
+            code_purpose: None,
+            supplier_bill_id: None,
+            tax_info_document_date: None,
+            tax_info_document_number: None,
+            tax_info_kbk: None,
+            tax_info_okato: None,
+            tax_info_period: None,
+            tax_info_reason_code: None,
+            tax_info_status: None,
+            budget_payment_code: None,
+        }
+    }
+
+    pub fn counterparty_inn(mut self, inn: impl Into<String>) -> Self {
+        self.counterparty_inn = Some(inn.into());
+        self
+    }
+
+    pub fn counterparty_kpp(mut self, kpp: impl Into<String>) -> Self {
+        self.counterparty_kpp = Some(kpp.into());
+        self
+    }
+
+    pub fn counterparty_bank_corr_account(mut self, account: impl Into<String>) -> Self {
+        self.counterparty_bank_corr_account = Some(account.into());
+        self
+    }
+
+    pub fn payment_number(mut self, number: impl Into<String>) -> Self {
+        self.payment_number = Some(number.into());
+        self
+    }
+
+    pub fn payment_priority(mut self, priority: impl Into<String>) -> Self {
+        self.payment_priority = Some(priority.into());
+        self
+    }
+
+    pub fn code_purpose(mut self, code: impl Into<String>) -> Self {
+        self.code_purpose = Some(code.into());
+        self
+    }
+
+    pub fn supplier_bill_id(mut self, uin: impl Into<String>) -> Self {
+        self.supplier_bill_id = Some(uin.into());
+        self
+    }
+
+    /// RU: Заполнить налоговые реквизиты бюджетного платежа.
+    /// EN: Fill budget payment fields from [`TaxFields`].
+    pub fn tax_fields(mut self, tax: TaxFields) -> Self {
+        self.tax_info_status = tax.originator_status;
+        self.tax_info_kbk = tax.kbk;
+        self.tax_info_okato = tax.oktmo;
+        self.tax_info_reason_code = tax.base;
+        self.tax_info_period = tax.field107;
+        self.tax_info_document_number = tax.document_number;
+        self.tax_info_document_date = tax.document_date.map(|d| match d {
+            DateValue::Text(text) => text,
+            DateValue::Number(number) => number.to_string(),
+        });
+        self.budget_payment_code = tax.type_;
+        self
+    }
+}
+
+/// RU: Ответ на создание платежа. EN: Payment order creation response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentOrderRequest {
+    /// RU: Идентификатор запроса на платёж. EN: Payment request ID.
+    pub request_id: String,
+    /// RU: Ссылка для подписания платежа в интернет-банке. EN: Redirect URL to sign the payment.
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: Option<String>,
+}
+
+/// RU: Статус платежа. EN: Payment order status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentOrderStatusInfo {
+    /// RU: Статус. EN: Status.
+    pub status: PaymentOrderStatus,
+    /// RU: Код ошибки. EN: Error code.
+    pub error_code: Option<String>,
+    /// RU: Описание ошибки. EN: Error description.
+    pub error_description: Option<String>,
+}
+
+/// RU: Статусы платёжного поручения. EN: Payment order statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PaymentOrderStatus {
+    /// RU: Запрос принят. EN: Request accepted.
+    Initiated,
+    /// RU: Ожидает подписи. EN: Waiting for signature.
+    #[serde(rename = "Wait For Sign")]
+    WaitForSign,
+    /// RU: Подписан, ожидает создания. EN: Signed, waiting for creation.
+    #[serde(rename = "Wait For Creation")]
+    WaitForCreation,
+    /// RU: Платёж создан. EN: Payment created.
+    Created,
+    /// RU: Отклонён. EN: Rejected.
+    Rejected,
+    /// RU: Ошибка. EN: Error.
+    Error,
+}
+
+/// RU: Параметры списка платежей на подпись. EN: Query for payments awaiting signature.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentForSignQuery {
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+    /// RU: Счёт (необязательно). EN: Account filter.
+    pub account_id: Option<String>,
+}
+
+impl PaymentForSignQuery {
+    /// RU: Создать запрос по коду клиента. EN: Build query by customer code.
+    pub fn new(customer_code: impl Into<String>) -> Self {
+        Self {
+            customer_code: customer_code.into(),
+            account_id: None,
+        }
+    }
+
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+}
+
+/// RU: Страница платежей на подпись. EN: Payments-for-sign page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentForSignPageData {
+    /// RU: Список платежей. EN: Payments list.
+    pub payment: Vec<PaymentOrderPayload>,
+}
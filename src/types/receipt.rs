@@ -1,5 +1,6 @@
-use crate::validate_phone;
-use crate::{PaymentMethod, PaymentObject, Supplier, VatType};
+use crate::{Money, PaymentMethod, PaymentObject, Supplier, VatType};
+use crate::{validate_phone, validate_positive_quantity};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -16,6 +17,27 @@ pub struct ReceiptClient {
     pub phone: Option<String>,
 }
 
+impl ReceiptClient {
+    /// RU: Покупатель, которому чек уйдёт на почту. EN: Buyer receiving the receipt by email.
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            email: email.into(),
+            phone: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+}
+
 #[derive(Serialize, Deserialize, Validate, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ReceiptItem {
@@ -27,10 +49,12 @@ pub struct ReceiptItem {
     pub name: String,
 
     /// Цена за единицу товара
-    pub amount: f64,
+    pub amount: Money,
 
     /// Количество товара
-    pub quantity: f64,
+    #[serde(with = "super::money::number")]
+    #[validate(custom(function = "validate_positive_quantity"))]
+    pub quantity: Decimal,
 
     /// Тип оплаты
     pub payment_method: Option<PaymentMethod>,
@@ -45,10 +69,58 @@ pub struct ReceiptItem {
 
     /// Данные поставщика
     #[serde(rename = "Supplier")]
+    #[validate(nested)]
     pub supplier: Option<Supplier>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ReceiptItem {
+    /// RU: Позиция чека: цена за единицу и количество. EN: Receipt line: unit price and quantity.
+    pub fn new(name: impl Into<String>, amount: Money, quantity: Decimal) -> Self {
+        Self {
+            vat_type: None,
+            name: name.into(),
+            amount,
+            quantity,
+            payment_method: None,
+            payment_object: None,
+            measure: None,
+            supplier: None,
+        }
+    }
+
+    pub fn vat_type(mut self, vat_type: VatType) -> Self {
+        self.vat_type = Some(vat_type);
+        self
+    }
+
+    pub fn payment_method(mut self, method: PaymentMethod) -> Self {
+        self.payment_method = Some(method);
+        self
+    }
+
+    pub fn payment_object(mut self, object: PaymentObject) -> Self {
+        self.payment_object = Some(object);
+        self
+    }
+
+    pub fn measure(mut self, measure: Measure) -> Self {
+        self.measure = Some(measure);
+        self
+    }
+
+    pub fn supplier(mut self, supplier: Supplier) -> Self {
+        self.supplier = Some(supplier);
+        self
+    }
+
+    /// RU: Стоимость позиции (цена × количество). EN: Line total (price × quantity).
+    pub fn total(&self) -> Money {
+        self.amount * self.quantity
+    }
+}
+
+/// RU: Единица измерения в документах выставления счетов. EN: Unit of measure for invoices.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum UnitCode {
     #[serde(rename = "шт.")]
     Pieces,
@@ -1,3 +1,7 @@
+use crate::{
+    Error, Money, OrderType, PaymentOperation, PaymentStatus, SbpPaymentStatus,
+    validate_positive_amount,
+};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -11,7 +15,7 @@ pub struct Refund {
     /// RU: Идентификатор операции. EN: Operation ID.
     pub operation_id: uuid::Uuid,
     /// RU: Сумма возврата. EN: Refund amount.
-    pub amount: f64,
+    pub amount: Money,
     /// RU: Дата возврата. EN: Refund date.
     pub date: NaiveDate,
     /// RU: Номер заказа/операции возврата. EN: Refund order identifier.
@@ -22,6 +26,188 @@ pub struct Refund {
 #[derive(Validate, Serialize, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RefundPayload {
-    #[validate(range(min = 0.0))]
-    pub amount: f64,
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
+}
+
+/// RU: Состояние возврата. EN: Refund state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefundStatus {
+    /// RU: Банк ещё проводит возврат (операция в `ON-REFUND`). EN: Refund is being processed.
+    InProgress,
+    /// RU: Возврат проведён. EN: Refund is completed.
+    Completed,
+}
+
+/// RU: Возврат по платёжной операции. EN: Refund recorded on a payment operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentRefund {
+    /// RU: Номер заказа возврата. EN: Refund order ID.
+    pub order_id: String,
+    /// RU: Сумма возврата. EN: Refund amount.
+    pub amount: Money,
+    /// RU: Время возврата. EN: Refund time.
+    pub time: String,
+    /// RU: Состояние возврата. EN: Refund state.
+    pub status: RefundStatus,
+}
+
+/// RU: Возвраты по платёжной операции. EN: Refund view over a payment operation.
+///
+/// Собирается из `Order` операции: списано — сумма `Approval` (или вся сумма оплаченной
+/// операции, если записей нет), возвращено — сумма `Refund`. Пока операция в статусе
+/// `ON-REFUND`, последний возврат считается [`RefundStatus::InProgress`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefundSummary {
+    /// RU: Идентификатор операции. EN: Operation ID.
+    pub operation_id: uuid::Uuid,
+    /// RU: Статус операции. EN: Operation status.
+    pub status: PaymentStatus,
+    /// RU: Списано с покупателя. EN: Captured amount.
+    pub captured: Money,
+    /// RU: Уже возвращено. EN: Refunded so far.
+    pub refunded: Money,
+    /// RU: Доступно для возврата. EN: Amount still refundable.
+    pub remaining: Money,
+    /// RU: История возвратов. EN: Refund history.
+    pub refunds: Vec<PaymentRefund>,
+}
+
+impl RefundSummary {
+    /// RU: Возврат по номеру заказа. EN: Refund by order ID.
+    pub fn refund(&self, order_id: &str) -> Option<&PaymentRefund> {
+        self.refunds
+            .iter()
+            .find(|refund| refund.order_id == order_id)
+    }
+
+    /// RU: Проверить, что сумму можно вернуть. EN: Check that the amount is refundable.
+    pub fn check_refund(&self, amount: Money) -> Result<(), Error> {
+        if amount.currency() != self.remaining.currency() || amount > self.remaining {
+            return Err(Error::RefundExceedsRemaining {
+                operation_id: self.operation_id.to_string(),
+                requested: amount,
+                remaining: self.remaining,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl From<&PaymentOperation> for RefundSummary {
+    fn from(operation: &PaymentOperation) -> Self {
+        let orders = operation.order.as_deref().unwrap_or_default();
+        let total = |order_type: OrderType| {
+            let amount = orders
+                .iter()
+                .filter(|order| order.order_type == order_type)
+                .map(|order| order.amount.amount())
+                .sum();
+            Money::new(amount, operation.amount.currency())
+        };
+        let paid = matches!(
+            operation.status,
+            PaymentStatus::Approved
+                | PaymentStatus::OnRefund
+                | PaymentStatus::Refunded
+                | PaymentStatus::RefundedPartially
+        );
+        let captured = match total(OrderType::Approval) {
+            approved if !approved.is_zero() => approved,
+            _ if paid => operation.amount,
+            zero => zero,
+        };
+        let refunded = total(OrderType::Refund);
+        let remaining = match captured.checked_sub(refunded) {
+            Some(remaining) if !remaining.is_negative() => remaining,
+            _ => Money::zero(captured.currency()),
+        };
+
+        let mut refunds: Vec<PaymentRefund> = orders
+            .iter()
+            .filter(|order| order.order_type == OrderType::Refund)
+            .map(|order| PaymentRefund {
+                order_id: order.order_id.clone(),
+                amount: order.amount,
+                time: order.time.clone(),
+                status: RefundStatus::Completed,
+            })
+            .collect();
+        if operation.status == PaymentStatus::OnRefund
+            && let Some(last) = refunds.last_mut()
+        {
+            last.status = RefundStatus::InProgress;
+        }
+
+        Self {
+            operation_id: operation.operation_id,
+            status: operation.status,
+            captured,
+            refunded,
+            remaining,
+            refunds,
+        }
+    }
+}
+
+/// RU: Запрос на возврат платежа СБП. EN: SBP payment refund payload.
+#[derive(Validate, Serialize, Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpRefundPayload {
+    /// RU: БИК банка. EN: Bank BIC.
+    #[validate(length(equal = 9))]
+    pub bank_code: String,
+    /// RU: Счёт, с которого делается возврат. EN: Refund source account.
+    #[validate(length(equal = 20))]
+    pub account_code: String,
+    /// RU: Сумма возврата в рублях. EN: Refund amount in rubles.
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
+    /// RU: Валюта (только RUB). EN: Currency (RUB only).
+    pub currency: String,
+    /// RU: Идентификатор QR-кода исходного платежа. EN: QR code ID of the original payment.
+    pub qrc_id: String,
+    /// RU: Назначение возврата. EN: Refund purpose.
+    #[validate(length(max = 140))]
+    pub purpose: Option<String>,
+    /// RU: Идентификатор операции СБП исходного платежа. EN: SBP transaction ID being refunded.
+    pub ref_transaction_id: String,
+}
+
+impl SbpRefundPayload {
+    /// RU: Возврат по операции СБП. EN: Refund of an SBP transaction.
+    pub fn new(
+        bank_code: impl Into<String>,
+        account_code: impl Into<String>,
+        amount: Money,
+        qrc_id: impl Into<String>,
+        ref_transaction_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            bank_code: bank_code.into(),
+            account_code: account_code.into(),
+            amount,
+            currency: "RUB".into(),
+            qrc_id: qrc_id.into(),
+            purpose: None,
+            ref_transaction_id: ref_transaction_id.into(),
+        }
+    }
+
+    pub fn purpose(mut self, purpose: impl Into<String>) -> Self {
+        self.purpose = Some(purpose.into());
+        self
+    }
+}
+
+/// RU: Возврат платежа СБП. EN: SBP refund request state.
+#[derive(Serialize, Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpRefund {
+    /// RU: Идентификатор запроса на возврат. EN: Refund request ID.
+    pub request_id: String,
+    /// RU: Статус возврата. EN: Refund status.
+    pub status: SbpPaymentStatus,
+    /// RU: Сообщение. EN: Message.
+    pub status_description: Option<String>,
 }
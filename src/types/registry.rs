@@ -1,4 +1,4 @@
-use crate::{PaymentMode, PaymentStatus};
+use crate::{Money, PaymentMode, PaymentStatus};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -39,7 +39,7 @@ pub struct Registry {
     /// RU: Способ оплаты. EN: Payment mode.
     pub payment_type: PaymentMode,
     /// RU: Сумма всех позиций. EN: Total amount.
-    pub total_amount: f64,
+    pub total_amount: Money,
     /// RU: Идентификатор платежа. EN: Payment ID.
     pub payment_id: Option<String>,
     /// RU: Детализация платежей. EN: Payments breakdown.
@@ -55,7 +55,7 @@ pub struct RegistryPayment {
     /// RU: Статус платежа. EN: Payment status.
     pub status: PaymentStatus,
     /// RU: Сумма платежа. EN: Payment amount.
-    pub amount: f64,
+    pub amount: Money,
     /// RU: Идентификатор операции. EN: Operation ID.
     pub operation_id: Uuid,
     /// RU: Время платежа. EN: Payment time.
@@ -63,9 +63,9 @@ pub struct RegistryPayment {
     /// RU: Номер платежа. EN: Payment number.
     pub number: u32,
     /// RU: Комиссия. EN: Commission.
-    pub commission: f64,
+    pub commission: Money,
     /// RU: Сумма к зачислению. EN: Enrollment amount.
-    pub enrollment_amount: f64,
+    pub enrollment_amount: Money,
 }
 
 /// RU: Страница с реестром платежей. EN: Registry page payload.
@@ -0,0 +1,524 @@
+use crate::{Money, validate_positive_amount};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// RU: Тип QR-кода СБП. EN: SBP QR code type.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QrCodeType {
+    /// RU: Статический (многоразовый). EN: Static (reusable).
+    #[serde(rename = "01")]
+    Static,
+    /// RU: Динамический (одноразовый, с суммой). EN: Dynamic (single payment with amount).
+    #[serde(rename = "02")]
+    Dynamic,
+}
+
+/// RU: Статус QR-кода. EN: QR code status.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QrCodeStatus {
+    /// RU: Активен. EN: Active.
+    Active,
+    /// RU: Приостановлен. EN: Suspended.
+    Suspended,
+}
+
+/// RU: Параметры изображения QR-кода. EN: QR code image parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrImageParams {
+    /// RU: Ширина в пикселях. EN: Width in pixels.
+    pub width: u32,
+    /// RU: Высота в пикселях. EN: Height in pixels.
+    pub height: u32,
+    /// RU: MIME-тип (`image/png`, `image/svg+xml`). EN: MIME type.
+    pub media_type: String,
+}
+
+impl Default for QrImageParams {
+    fn default() -> Self {
+        Self {
+            width: 300,
+            height: 300,
+            media_type: "image/png".into(),
+        }
+    }
+}
+
+/// RU: Изображение QR-кода. EN: QR code image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrImage {
+    /// RU: Ширина. EN: Width.
+    pub width: u32,
+    /// RU: Высота. EN: Height.
+    pub height: u32,
+    /// RU: MIME-тип. EN: MIME type.
+    pub media_type: String,
+    /// RU: Содержимое в base64. EN: Base64-encoded content.
+    pub content: String,
+}
+
+/// RU: Запрос на регистрацию QR-кода. EN: QR code registration payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodePayload {
+    /// RU: Сумма, в API — в копейках (обязательна для динамического QR). EN: Amount, sent in kopecks.
+    #[serde(default, with = "super::money::kopecks::option")]
+    pub amount: Option<Money>,
+    /// RU: Валюта (только RUB). EN: Currency (RUB only).
+    pub currency: Option<String>,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    #[validate(length(max = 140))]
+    pub payment_purpose: String,
+    /// RU: Тип QR-кода. EN: QR code type.
+    pub qrc_type: QrCodeType,
+    /// RU: Параметры изображения. EN: Image parameters.
+    pub image_params: Option<QrImageParams>,
+    /// RU: Название источника (CMS, касса). EN: Source name.
+    pub source_name: Option<String>,
+    /// RU: Время жизни динамического QR в минутах. EN: Dynamic QR TTL in minutes.
+    pub ttl: Option<u32>,
+    /// RU: Адрес возврата после оплаты. EN: Redirect URL after payment.
+    #[validate(url)]
+    pub redirect_url: Option<String>,
+}
+
+impl QrCodePayload {
+    /// RU: Статический QR-код. EN: Static QR code.
+    pub fn static_qr(payment_purpose: impl Into<String>) -> Self {
+        Self {
+            amount: None,
+            currency: None,
+            payment_purpose: payment_purpose.into(),
+            qrc_type: QrCodeType::Static,
+            image_params: None,
+            source_name: None,
+            ttl: None,
+            redirect_url: None,
+        }
+    }
+
+    /// RU: Динамический QR-код на сумму. EN: Dynamic QR code for an amount.
+    pub fn dynamic_qr(amount: Money, payment_purpose: impl Into<String>) -> Self {
+        Self {
+            amount: Some(amount),
+            currency: Some("RUB".into()),
+            qrc_type: QrCodeType::Dynamic,
+            ..Self::static_qr(payment_purpose)
+        }
+    }
+
+    pub fn image_params(mut self, params: QrImageParams) -> Self {
+        self.image_params = Some(params);
+        self
+    }
+
+    pub fn source_name(mut self, name: impl Into<String>) -> Self {
+        self.source_name = Some(name.into());
+        self
+    }
+
+    pub fn ttl(mut self, minutes: u32) -> Self {
+        self.ttl = Some(minutes);
+        self
+    }
+
+    pub fn redirect_url(mut self, url: impl Into<String>) -> Self {
+        self.redirect_url = Some(url.into());
+        self
+    }
+}
+
+/// RU: Зарегистрированный QR-код. EN: Registered QR code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredQrCode {
+    /// RU: Идентификатор QR-кода в СБП. EN: SBP QR code ID.
+    pub qrc_id: String,
+    /// RU: Ссылка-содержимое QR-кода. EN: QR payload URL.
+    pub payload: String,
+    /// RU: Изображение. EN: Image.
+    pub image: Option<QrImage>,
+}
+
+/// RU: QR-код СБП. EN: SBP QR code details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCode {
+    /// RU: Идентификатор QR-кода. EN: QR code ID.
+    pub qrc_id: String,
+    /// RU: Счёт зачисления. EN: Settlement account.
+    pub account_id: Option<String>,
+    /// RU: Статус. EN: Status.
+    pub status: QrCodeStatus,
+    /// RU: Время создания. EN: Creation time.
+    pub created_at: Option<DateTime<Utc>>,
+    /// RU: Идентификатор юрлица в СБП. EN: SBP legal entity ID.
+    pub legal_id: Option<String>,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: Option<String>,
+    /// RU: Сумма, в API — в копейках. EN: Amount, sent in kopecks.
+    #[serde(default, with = "super::money::kopecks::option")]
+    pub amount: Option<Money>,
+    /// RU: Комиссия в процентах. EN: Commission percent.
+    pub commission_percent: Option<f64>,
+    /// RU: Валюта. EN: Currency.
+    pub currency: Option<String>,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    pub payment_purpose: Option<String>,
+    /// RU: Тип QR-кода. EN: QR code type.
+    pub qrc_type: QrCodeType,
+    /// RU: Версия шаблона. EN: Template version.
+    pub template_version: Option<String>,
+    /// RU: Ссылка-содержимое. EN: QR payload URL.
+    pub payload: Option<String>,
+    /// RU: Изображение. EN: Image.
+    pub image: Option<QrImage>,
+    /// RU: Название источника. EN: Source name.
+    pub source_name: Option<String>,
+    /// RU: Время жизни в минутах. EN: TTL in minutes.
+    pub ttl: Option<u32>,
+}
+
+/// RU: Список QR-кодов. EN: QR code list page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodePageData {
+    /// RU: QR-коды. EN: QR codes.
+    pub qr_code_list: Vec<QrCode>,
+}
+
+/// RU: Статус платежа СБП. EN: SBP payment status.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SbpPaymentStatus {
+    /// RU: Операции нет. EN: No operation yet.
+    NotStarted,
+    /// RU: Получен запрос. EN: Request received.
+    Received,
+    /// RU: В обработке. EN: In progress.
+    InProgress,
+    /// RU: Оплачено. EN: Accepted.
+    Accepted,
+    /// RU: Отклонено. EN: Rejected.
+    Rejected,
+}
+
+/// RU: Статус оплаты по QR-коду. EN: Payment status for a QR code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodePayment {
+    /// RU: Идентификатор QR-кода. EN: QR code ID.
+    pub qrc_id: String,
+    /// RU: Код ответа. EN: Response code.
+    pub code: Option<String>,
+    /// RU: Статус операции. EN: Operation status.
+    pub status: SbpPaymentStatus,
+    /// RU: Сообщение. EN: Message.
+    pub message: Option<String>,
+    /// RU: Идентификатор операции СБП. EN: SBP transaction ID.
+    pub trx_id: Option<String>,
+}
+
+/// RU: Статусы оплат по QR-кодам. EN: QR code payment statuses page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodePaymentPageData {
+    /// RU: Список статусов. EN: Status list.
+    pub payment_list: Vec<QrCodePayment>,
+}
+
+/// RU: Статус юрлица, ТСП или счёта в СБП. EN: SBP legal entity, merchant or account status.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SbpEntityStatus {
+    /// RU: Активен. EN: Active.
+    Active,
+    /// RU: Приостановлен. EN: Suspended.
+    Suspended,
+}
+
+/// RU: Запрос смены статуса. EN: Status change payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SbpStatusPayload {
+    /// RU: Новый статус. EN: New status.
+    pub status: SbpEntityStatus,
+}
+
+/// RU: Запрос на регистрацию юрлица в СБП. EN: SBP legal entity registration payload.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpLegalEntityPayload {
+    /// RU: Код клиента. EN: Customer code.
+    #[validate(length(equal = 9))]
+    pub customer_code: String,
+    /// RU: БИК банка. EN: Bank BIC.
+    #[validate(length(equal = 9))]
+    pub bank_code: String,
+}
+
+/// RU: Ответ на регистрацию юрлица. EN: Legal entity registration response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpLegalEntityRegistered {
+    /// RU: Идентификатор юрлица в СБП. EN: SBP legal entity ID.
+    pub legal_id: String,
+}
+
+/// RU: Юрлицо в СБП. EN: SBP legal entity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpLegalEntity {
+    /// RU: Идентификатор юрлица. EN: Legal entity ID.
+    pub legal_id: String,
+    /// RU: Статус. EN: Status.
+    pub status: SbpEntityStatus,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: Option<String>,
+    /// RU: Наименование. EN: Name.
+    pub name: Option<String>,
+    /// RU: ИНН. EN: Tax code.
+    pub inn: Option<String>,
+    /// RU: КПП. EN: KPP.
+    pub kpp: Option<String>,
+    /// RU: ОГРН. EN: OGRN.
+    pub ogrn: Option<String>,
+    /// RU: Юридический адрес. EN: Legal address.
+    pub address: Option<String>,
+    /// RU: Время регистрации. EN: Registration time.
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// RU: Возможности ТСП. EN: Merchant capabilities.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SbpMerchantCapabilities {
+    /// RU: Только статические QR. EN: Static QR codes only.
+    #[serde(rename = "001")]
+    Static,
+    /// RU: Только динамические QR. EN: Dynamic QR codes only.
+    #[serde(rename = "010")]
+    Dynamic,
+    /// RU: Статические и динамические QR. EN: Static and dynamic QR codes.
+    #[serde(rename = "011")]
+    StaticAndDynamic,
+    /// RU: Только кассовые QR. EN: Cashbox QR codes only.
+    #[serde(rename = "100")]
+    Cashbox,
+    /// RU: Кассовые и статические QR. EN: Cashbox and static QR codes.
+    #[serde(rename = "101")]
+    CashboxAndStatic,
+    /// RU: Кассовые и динамические QR. EN: Cashbox and dynamic QR codes.
+    #[serde(rename = "110")]
+    CashboxAndDynamic,
+    /// RU: Все виды QR. EN: All QR code kinds.
+    #[serde(rename = "111")]
+    All,
+}
+
+/// RU: Дополнительный контакт ТСП. EN: Additional merchant contact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SbpContact {
+    /// RU: Тип контакта (`email`, `phone`). EN: Contact type.
+    #[serde(rename = "type")]
+    pub contact_type: String,
+    /// RU: Значение. EN: Value.
+    pub value: String,
+}
+
+/// RU: Запрос на регистрацию ТСП. EN: Merchant registration payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpMerchantPayload {
+    /// RU: Адрес ТСП. EN: Merchant address.
+    #[validate(length(min = 1, max = 160))]
+    pub address: String,
+    /// RU: Город. EN: City.
+    #[validate(length(min = 1, max = 40))]
+    pub city: String,
+    /// RU: Код страны (RU). EN: Country code.
+    #[validate(length(equal = 2))]
+    pub country_code: String,
+    /// RU: Код региона (ОКАТО). EN: Region code.
+    #[validate(length(equal = 2))]
+    pub country_sub_division_code: String,
+    /// RU: Почтовый индекс. EN: Postal code.
+    #[validate(length(equal = 6))]
+    pub zip_code: String,
+    /// RU: Вывеска. EN: Brand name.
+    #[validate(length(min = 1, max = 100))]
+    pub brand_name: String,
+    /// RU: Возможности ТСП. EN: Capabilities.
+    pub capabilities: SbpMerchantCapabilities,
+    /// RU: Контактный телефон. EN: Contact phone.
+    pub contact_phone_number: Option<String>,
+    /// RU: MCC. EN: Merchant category code.
+    #[validate(length(equal = 4))]
+    pub mcc: String,
+    /// RU: Дополнительные контакты. EN: Additional contacts.
+    pub additional_contacts: Option<Vec<SbpContact>>,
+}
+
+/// RU: Ответ на регистрацию ТСП. EN: Merchant registration response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpMerchantRegistered {
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: String,
+}
+
+/// RU: ТСП в СБП. EN: SBP merchant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpMerchant {
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: String,
+    /// RU: Идентификатор юрлица. EN: Legal entity ID.
+    pub legal_id: Option<String>,
+    /// RU: Статус. EN: Status.
+    pub status: SbpEntityStatus,
+    /// RU: Вывеска. EN: Brand name.
+    pub brand_name: Option<String>,
+    /// RU: Возможности. EN: Capabilities.
+    pub capabilities: Option<SbpMerchantCapabilities>,
+    /// RU: MCC. EN: Merchant category code.
+    pub mcc: Option<String>,
+    /// RU: Адрес. EN: Address.
+    pub address: Option<String>,
+    /// RU: Город. EN: City.
+    pub city: Option<String>,
+    /// RU: Почтовый индекс. EN: Postal code.
+    pub zip_code: Option<String>,
+    /// RU: Контактный телефон. EN: Contact phone.
+    pub contact_phone_number: Option<String>,
+}
+
+/// RU: Список ТСП. EN: Merchant list page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpMerchantPageData {
+    /// RU: ТСП. EN: Merchants.
+    pub merchant_list: Vec<SbpMerchant>,
+}
+
+/// RU: Счёт, подключённый к СБП. EN: Account linked to SBP.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpAccount {
+    /// RU: Номер счёта. EN: Account number.
+    pub account_id: String,
+    /// RU: Статус. EN: Status.
+    pub status: SbpEntityStatus,
+    /// RU: Время подключения. EN: Link time.
+    pub created_at: Option<DateTime<Utc>>,
+    /// RU: Идентификатор юрлица. EN: Legal entity ID.
+    pub legal_id: Option<String>,
+}
+
+/// RU: Список счетов СБП. EN: SBP account list page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpAccountPageData {
+    /// RU: Счета. EN: Accounts.
+    pub account_list: Vec<SbpAccount>,
+}
+
+/// RU: Запрос на регистрацию кассового QR-кода. EN: Cashbox QR code registration payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CashboxQrCodePayload {
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: String,
+    /// RU: Счёт зачисления. EN: Settlement account.
+    #[validate(length(equal = 20))]
+    pub account_id: String,
+    /// RU: Адрес возврата после оплаты. EN: Redirect URL after payment.
+    #[validate(url)]
+    pub redirect_url: Option<String>,
+    /// RU: Параметры изображения. EN: Image parameters.
+    pub image_params: Option<QrImageParams>,
+}
+
+impl CashboxQrCodePayload {
+    /// RU: Кассовый QR-код для ТСП и счёта. EN: Cashbox QR code for a merchant and account.
+    pub fn new(merchant_id: impl Into<String>, account_id: impl Into<String>) -> Self {
+        Self {
+            merchant_id: merchant_id.into(),
+            account_id: account_id.into(),
+            redirect_url: None,
+            image_params: None,
+        }
+    }
+
+    pub fn redirect_url(mut self, url: impl Into<String>) -> Self {
+        self.redirect_url = Some(url.into());
+        self
+    }
+
+    pub fn image_params(mut self, params: QrImageParams) -> Self {
+        self.image_params = Some(params);
+        self
+    }
+}
+
+/// RU: Параметры активации кассового QR-кода. EN: Cashbox QR code activation payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CashboxQrActivationPayload {
+    /// RU: Сумма, в API — в копейках. EN: Amount, sent in kopecks.
+    #[serde(with = "super::money::kopecks")]
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
+    /// RU: Валюта (только RUB). EN: Currency (RUB only).
+    pub currency: String,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    #[validate(length(max = 140))]
+    pub payment_purpose: Option<String>,
+    /// RU: Время жизни активации в минутах. EN: Activation TTL in minutes.
+    pub ttl: Option<u32>,
+}
+
+impl CashboxQrActivationPayload {
+    /// RU: Активация на сумму. EN: Activation for an amount.
+    pub fn new(amount: Money) -> Self {
+        Self {
+            amount,
+            currency: "RUB".into(),
+            payment_purpose: None,
+            ttl: None,
+        }
+    }
+
+    pub fn payment_purpose(mut self, purpose: impl Into<String>) -> Self {
+        self.payment_purpose = Some(purpose.into());
+        self
+    }
+
+    pub fn ttl(mut self, minutes: u32) -> Self {
+        self.ttl = Some(minutes);
+        self
+    }
+}
+
+/// RU: Ответ на активацию кассового QR-кода. EN: Cashbox QR code activation response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CashboxQrActivation {
+    /// RU: Идентификатор параметров активации. EN: Activation parameters ID.
+    pub params_id: String,
+}
+
+/// RU: Статус оплаты по кассовому QR-коду. EN: Cashbox QR code payment status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CashboxQrPayment {
+    /// RU: Идентификатор параметров активации. EN: Activation parameters ID.
+    pub params_id: String,
+    /// RU: Статус операции. EN: Operation status.
+    pub status: SbpPaymentStatus,
+    /// RU: Сообщение. EN: Message.
+    pub message: Option<String>,
+    /// RU: Идентификатор операции СБП. EN: SBP transaction ID.
+    pub trx_id: Option<String>,
+    /// RU: Сумма, в API — в копейках. EN: Amount, sent in kopecks.
+    #[serde(default, with = "super::money::kopecks::option")]
+    pub amount: Option<Money>,
+}
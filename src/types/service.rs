@@ -1,5 +1,5 @@
 /// RU: Сервисы API Tochka. EN: Tochka API services.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Service {
     /// RU: Open Banking. EN: Open Banking.
     OpenBanking,
@@ -18,6 +18,26 @@ pub enum Service {
 }
 
 impl Service {
+    /// RU: Все сервисы. EN: All services.
+    pub const ALL: [Service; 7] = [
+        Service::OpenBanking,
+        Service::Payment,
+        Service::Acquiring,
+        Service::Invoice,
+        Service::Consent,
+        Service::Sbp,
+        Service::Webhook,
+    ];
+
+    /// RU: Определить сервис по пути запроса (`acquiring/v1.0/...`).
+    /// EN: Resolve the service from a request path (`acquiring/v1.0/...`).
+    pub fn from_path(path: &str) -> Option<Service> {
+        let segment = path.trim_start_matches('/').split('/').next()?;
+        Self::ALL
+            .into_iter()
+            .find(|service| service.path() == segment)
+    }
+
     /// RU: Путь сервиса в URL. EN: URL path segment for the service.
     pub fn path(&self) -> &'static str {
         match self {
@@ -1,6 +1,7 @@
-use crate::TransactionStatement;
+use crate::{Money, TransactionStatement};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use validator::Validate;
 
 /// RU: Выписка по счёту. EN: Account statement.
@@ -22,9 +23,9 @@ pub struct Statement {
     /// RU: Время создания ресурса. EN: Resource creation time.
     pub creation_date_time: DateTime<Utc>,
     /// RU: Баланс на начало периода. EN: Starting balance.
-    pub start_date_balance: Option<f64>,
+    pub start_date_balance: Option<Money>,
     /// RU: Баланс на конец периода. EN: Closing balance.
-    pub end_date_balance: Option<f64>,
+    pub end_date_balance: Option<Money>,
     #[serde(rename = "Transaction")]
     /// RU: Транзакции выписки. EN: Statement transactions.
     pub transaction: Option<Vec<TransactionStatement>>,
@@ -41,7 +42,7 @@ pub struct StatementPayload {
 }
 
 /// RU: Статус выписки. EN: Statement status.
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
 pub enum StatementStatus {
     #[default]
     Created,
@@ -56,3 +57,46 @@ pub enum StatementStatus {
 pub struct StatementPageData {
     pub statement: Vec<Statement>,
 }
+
+/// RU: Настройки ожидания готовности выписки. EN: Statement polling settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollOptions {
+    /// RU: Пауза перед первой проверкой, не меньше [`PollOptions::MIN_INTERVAL`].
+    /// EN: Delay before the first check, at least [`PollOptions::MIN_INTERVAL`].
+    pub interval: Duration,
+    /// RU: Предельная пауза между проверками. EN: Upper bound for the delay.
+    pub max_interval: Duration,
+    /// RU: Сколько всего ждать. EN: Overall timeout.
+    pub timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl PollOptions {
+    /// RU: Минимальная пауза между проверками: меньшие значения, включая ноль, поднимаются до неё.
+    /// EN: Lower bound for the delay; smaller values, including zero, are raised to it.
+    pub const MIN_INTERVAL: Duration = Duration::from_millis(10);
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(Self::MIN_INTERVAL);
+        self
+    }
+
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
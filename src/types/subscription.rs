@@ -0,0 +1,237 @@
+use crate::{Money, PageItems, validate_positive_amount};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// RU: Статус подписки. EN: Subscription status.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionStatus {
+    /// RU: Создана, первый платёж ещё не прошёл. EN: Created, first payment pending.
+    #[serde(alias = "CREATED")]
+    Created,
+    /// RU: Действует. EN: Active.
+    #[serde(alias = "ACTIVE")]
+    Active,
+    /// RU: Отменена. EN: Cancelled.
+    #[serde(alias = "CANCELLED")]
+    Cancelled,
+}
+
+/// RU: Период списаний по подписке. EN: Subscription billing period.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionPeriod {
+    /// RU: Ежедневно. EN: Daily.
+    Day,
+    /// RU: Еженедельно. EN: Weekly.
+    Week,
+    /// RU: Ежемесячно. EN: Monthly.
+    Month,
+}
+
+/// RU: Расписание регулярных списаний. EN: Recurring charge schedule.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionSchedule {
+    /// RU: Сколько раз списать; `None` — бессрочно. EN: Number of charges, `None` for unlimited.
+    #[validate(range(min = 1))]
+    pub tranche_count: Option<u32>,
+    /// RU: Период. EN: Billing period.
+    pub period: SubscriptionPeriod,
+    /// RU: День периода, в который списывать. EN: Day of the period to charge on.
+    pub days_in_period: Option<u32>,
+}
+
+impl SubscriptionSchedule {
+    /// RU: Списание раз в `period`. EN: Charge once per `period`.
+    pub fn every(period: SubscriptionPeriod) -> Self {
+        Self {
+            tranche_count: None,
+            period,
+            days_in_period: None,
+        }
+    }
+
+    pub fn tranche_count(mut self, count: u32) -> Self {
+        self.tranche_count = Some(count);
+        self
+    }
+
+    pub fn days_in_period(mut self, day: u32) -> Self {
+        self.days_in_period = Some(day);
+        self
+    }
+}
+
+/// RU: Запрос на создание подписки. EN: Subscription creation payload.
+///
+/// Первый платёж покупатель проходит по ссылке, карта сохраняется. Дальше банк списывает
+/// сумму по `Options` или, для `recurring`, по запросу
+/// [`Client::charge_subscription`](crate::Client::charge_subscription).
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionPayload {
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: Option<String>,
+    /// RU: Сумма каждого списания. EN: Amount of every charge.
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    #[validate(length(max = 140))]
+    pub purpose: String,
+    /// RU: Куда вернуть покупателя после оплаты. EN: Redirect URL after a successful payment.
+    pub redirect_url: Option<String>,
+    /// RU: Куда вернуть покупателя при ошибке. EN: Redirect URL after a failed payment.
+    pub fail_redirect_url: Option<String>,
+    /// RU: Сохранить карту покупателя. EN: Save the buyer's card.
+    pub save_card: Option<bool>,
+    /// RU: Идентификатор покупателя. EN: Consumer ID.
+    pub consumer_id: Option<Uuid>,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: Option<String>,
+    /// RU: Списания по запросу вместо расписания. EN: On-demand charges instead of a schedule.
+    pub recurring: Option<bool>,
+    /// RU: Расписание списаний. EN: Charge schedule.
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub options: Option<SubscriptionSchedule>,
+}
+
+impl CreateSubscriptionPayload {
+    pub fn new(amount: Money, customer_code: Option<String>, purpose: impl Into<String>) -> Self {
+        Self {
+            customer_code,
+            amount,
+            purpose: purpose.into(),
+            redirect_url: None,
+            fail_redirect_url: None,
+            save_card: Some(true),
+            consumer_id: None,
+            merchant_id: None,
+            recurring: None,
+            options: None,
+        }
+    }
+
+    /// RU: Списывать по расписанию. EN: Charge on a schedule.
+    pub fn schedule(mut self, schedule: SubscriptionSchedule) -> Self {
+        self.recurring = Some(false);
+        self.options = Some(schedule);
+        self
+    }
+
+    /// RU: Списывать по запросу. EN: Charge on demand.
+    pub fn on_demand(mut self) -> Self {
+        self.recurring = Some(true);
+        self.options = None;
+        self
+    }
+
+    pub fn redirect_url(mut self, redirect_url: impl Into<String>) -> Self {
+        self.redirect_url = Some(redirect_url.into());
+        self
+    }
+
+    pub fn fail_redirect_url(mut self, fail_redirect_url: impl Into<String>) -> Self {
+        self.fail_redirect_url = Some(fail_redirect_url.into());
+        self
+    }
+
+    pub fn consumer_id(mut self, id: Uuid) -> Self {
+        self.consumer_id = Some(id);
+        self
+    }
+
+    pub fn merchant_id(mut self, id: impl Into<String>) -> Self {
+        self.merchant_id = Some(id.into());
+        self
+    }
+}
+
+/// RU: Подписка эквайринга. EN: Acquiring subscription.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    /// RU: Идентификатор подписки. EN: Subscription (operation) ID.
+    pub operation_id: Uuid,
+    /// RU: Статус. EN: Status.
+    pub status: SubscriptionStatus,
+    /// RU: Сумма списания. EN: Charge amount.
+    pub amount: Money,
+    /// RU: Назначение. EN: Purpose.
+    pub purpose: Option<String>,
+    /// RU: Ссылка на первый платёж. EN: Link for the first payment.
+    pub payment_link: Option<String>,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: Option<String>,
+    /// RU: Идентификатор покупателя. EN: Consumer ID.
+    pub consumer_id: Option<Uuid>,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: Option<String>,
+    /// RU: Списания по запросу. EN: On-demand charges.
+    pub recurring: Option<bool>,
+    /// RU: Расписание. EN: Schedule.
+    #[serde(rename = "Options")]
+    pub options: Option<SubscriptionSchedule>,
+    /// RU: Время создания. EN: Creation time.
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// RU: Страница подписок. EN: Subscription list page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriptionPageData {
+    pub subscription: Vec<Subscription>,
+}
+
+impl PageItems for SubscriptionPageData {
+    type Item = Subscription;
+
+    fn into_items(self) -> Vec<Subscription> {
+        self.subscription
+    }
+}
+
+/// RU: Параметры списка подписок. EN: Subscription list query.
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionListQuery {
+    /// Уникальный код клиента
+    pub customer_code: Option<String>,
+    /// Номер страницы
+    pub page: Option<u32>,
+    /// Количество записей на странице
+    pub per_page: Option<u32>,
+}
+
+impl SubscriptionListQuery {
+    pub fn new(customer_code: Option<String>) -> Self {
+        Self {
+            customer_code,
+            ..Default::default()
+        }
+    }
+
+    pub fn page(mut self, v: u32) -> Self {
+        self.page = Some(v);
+        self
+    }
+
+    pub fn per_page(mut self, v: u32) -> Self {
+        self.per_page = Some(v);
+        self
+    }
+}
+
+/// RU: Статус подписки в запросе и ответе. EN: Subscription status body.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionStatusBody {
+    pub status: SubscriptionStatus,
+}
+
+/// RU: Запрос на списание с сохранённой карты. EN: On-demand charge payload.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+pub struct ChargeSubscriptionPayload {
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Money,
+}
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
@@ -32,7 +33,7 @@ pub enum TaxSystemCode {
     Envd,
 }
 
-#[derive(Deserialize, Serialize, EnumString, Display, Debug, Clone)]
+#[derive(Deserialize, Serialize, EnumString, Display, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum VatType {
     None,
@@ -47,13 +48,52 @@ pub enum VatType {
     Vat120,
 }
 
-#[derive(Deserialize, Serialize, EnumString, Display)]
-#[serde(rename_all = "snake_case")]
-pub enum NdsKind {
-    Nds0,
-    Nds5,
-    Nds7,
-    Nds10,
-    Nds20,
-    WithoutNds,
+impl VatType {
+    /// RU: Ставка в процентах; у расчётных ставок (`vat120`) — та же ставка НДС.
+    /// EN: Rate in percent; calculated rates (`vat120`) map to the same VAT rate.
+    pub fn rate(&self) -> Decimal {
+        match self {
+            VatType::None | VatType::Vat0 => Decimal::ZERO,
+            VatType::Vat5 | VatType::Vat105 => Decimal::from(5),
+            VatType::Vat7 | VatType::Vat107 => Decimal::from(7),
+            VatType::Vat10 | VatType::Vat110 => Decimal::TEN,
+            VatType::Vat20 | VatType::Vat120 => Decimal::from(20),
+        }
+    }
+}
+
+/// Ставка НДС в документах выставления счетов: `#[serde(with = "super::tax::nds_kind")]`.
+///
+/// Сервис счетов называет ставки иначе, чем чеки (`nds_20`, `without_nds`), и не знает
+/// расчётных ставок, поэтому `vat120` уходит как `nds_20`.
+pub(crate) mod nds_kind {
+    use super::VatType;
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    pub fn serialize<S: Serializer>(vat: &VatType, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match vat {
+            VatType::None => "without_nds",
+            VatType::Vat0 => "nds_0",
+            VatType::Vat5 | VatType::Vat105 => "nds_5",
+            VatType::Vat7 | VatType::Vat107 => "nds_7",
+            VatType::Vat10 | VatType::Vat110 => "nds_10",
+            VatType::Vat20 | VatType::Vat120 => "nds_20",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<VatType, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.as_str() {
+            "without_nds" => Ok(VatType::None),
+            "nds_0" => Ok(VatType::Vat0),
+            "nds_5" => Ok(VatType::Vat5),
+            "nds_7" => Ok(VatType::Vat7),
+            "nds_10" => Ok(VatType::Vat10),
+            "nds_20" => Ok(VatType::Vat20),
+            other => Err(de::Error::unknown_variant(
+                other,
+                &["without_nds", "nds_0", "nds_5", "nds_7", "nds_10", "nds_20"],
+            )),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{CashAccount, Contractor, ContractorBank, CreditDebitIndicator, TaxFields};
+use crate::{CashAccount, Contractor, ContractorBank, CreditDebitIndicator, Money, TaxFields};
 use chrono::{DateTime, NaiveDate, Utc};
 use codes_iso_4217::CurrencyCode;
 use serde::{Deserialize, Serialize};
@@ -26,23 +26,84 @@ pub struct Transaction {
 }
 
 /// RU: Сумма транзакции. EN: Transaction amount.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
-#[serde(rename_all = "camelCase")]
+///
+/// В JSON это `amount` и `currency`; валюта переносится в [`Money`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "AmountRepr", into = "AmountRepr")]
 pub struct Amount {
-    /// RU: Сумма. EN: Amount.
-    pub amount: f64,
+    /// RU: Сумма в валюте ISO 4217. EN: Amount in ISO 4217 currency.
+    pub amount: Money,
     /// RU: Сумма в копейках (если передана). EN: Amount in minor units if provided.
     pub amount_nat: Option<u32>,
-    /// RU: Валюта ISO 4217. EN: Currency ISO 4217.
-    pub currency: CurrencyCode,
 }
 
-/// RU: Сумма в валюте счёта. EN: Amount in account currency.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+impl Amount {
+    pub fn currency(&self) -> CurrencyCode {
+        self.amount.currency()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+struct AmountRepr {
+    amount: Money,
+    amount_nat: Option<u32>,
+    currency: CurrencyCode,
+}
+
+impl From<AmountRepr> for Amount {
+    fn from(repr: AmountRepr) -> Self {
+        Self {
+            amount: repr.amount.with_currency(repr.currency),
+            amount_nat: repr.amount_nat,
+        }
+    }
+}
+
+impl From<Amount> for AmountRepr {
+    fn from(amount: Amount) -> Self {
+        Self {
+            currency: amount.currency(),
+            amount: amount.amount,
+            amount_nat: amount.amount_nat,
+        }
+    }
+}
+
+/// RU: Сумма в валюте счёта. EN: Amount in account currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "AccountAmountRepr", into = "AccountAmountRepr")]
 pub struct AccountAmount {
-    pub amount: f64,
-    pub currency: CurrencyCode,
+    pub amount: Money,
+}
+
+impl AccountAmount {
+    pub fn currency(&self) -> CurrencyCode {
+        self.amount.currency()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AccountAmountRepr {
+    amount: Money,
+    currency: CurrencyCode,
+}
+
+impl From<AccountAmountRepr> for AccountAmount {
+    fn from(repr: AccountAmountRepr) -> Self {
+        Self {
+            amount: repr.amount.with_currency(repr.currency),
+        }
+    }
+}
+
+impl From<AccountAmount> for AccountAmountRepr {
+    fn from(amount: AccountAmount) -> Self {
+        Self {
+            currency: amount.currency(),
+            amount: amount.amount,
+        }
+    }
 }
 
 /// RU: Информация о терминале. EN: Terminal information.
@@ -63,14 +124,14 @@ pub struct TransactionPageData {
 }
 
 /// RU: Статус проводки. EN: Transaction status.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 pub enum TransactionStatus {
     Booked,
     Pending,
 }
 
 /// RU: Транзакция в выписке. EN: Statement transaction entry.
-#[derive(Validate, Serialize, Debug, Deserialize)]
+#[derive(Validate, Serialize, Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionStatement {
     /// RU: Идентификатор транзакции. EN: Transaction ID.
@@ -95,7 +156,7 @@ pub struct TransactionStatement {
 }
 
 /// RU: Детализация сумм и контрагентов. EN: Amount and counterparty details.
-#[derive(Validate, Serialize, Debug, Deserialize)]
+#[derive(Validate, Serialize, Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct TransactionSubfields {
     pub amount: Amount,
@@ -170,3 +231,30 @@ pub enum TransationTypeCode {
     #[serde(rename = "Импортированная запись")]
     ImportedRecord,
 }
+
+impl TransationTypeCode {
+    /// RU: Название вида документа, как в API и в 1С. EN: Document type name as sent by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransationTypeCode::Undefined => "Неопределенное значение",
+            TransationTypeCode::PaymentOrder => "Платежное поручение",
+            TransationTypeCode::PaymentRequest => "Платежное требование",
+            TransationTypeCode::CashCheckRko => "Денежный чек, РКО",
+            TransationTypeCode::CashDepositPko => "Объявление на взнос наличными, ПКО",
+            TransationTypeCode::DemandOrder => "Требование-поручение",
+            TransationTypeCode::CollectionOrder => "Инкассовое поручение",
+            TransationTypeCode::SettlementCheck => "Расчетный чек",
+            TransationTypeCode::LetterOfCredit => "Аккредитив",
+            TransationTypeCode::MemorialOrder => "Мемориальный ордер",
+            TransationTypeCode::LoanRepayment => "Погашение кредита",
+            TransationTypeCode::LoanIssuance => "Выдача кредита",
+            TransationTypeCode::Aviso => "Авизо",
+            TransationTypeCode::BankCards => "Банковские карты",
+            TransationTypeCode::PaymentInstruction => "Платежный ордер",
+            TransationTypeCode::BankOrder => "Банковский ордер",
+            TransationTypeCode::AssetTransferOrder => "Ордер по передаче ценностей",
+            TransationTypeCode::ProgramOrder => "Программный ордер",
+            TransationTypeCode::ImportedRecord => "Импортированная запись",
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 /// RU: Версия API. EN: API version.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub enum ApiVersion {
     /// RU: Текущая версия v1.0. EN: Current v1.0.
     #[serde(rename = "v1.0")]
+    #[default]
     V1_0,
 }
 
@@ -16,9 +17,3 @@ impl ApiVersion {
         }
     }
 }
-
-impl Default for ApiVersion {
-    fn default() -> Self {
-        ApiVersion::V1_0
-    }
-}
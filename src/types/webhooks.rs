@@ -1,5 +1,7 @@
-use crate::{PaymentMode, PaymentStatus};
-use serde::{Deserialize, Serialize};
+use crate::{Money, PaymentMode, PaymentStatus};
+use chrono::NaiveDate;
+use codes_iso_4217::CurrencyCode;
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -15,7 +17,7 @@ pub struct Webhook {
 }
 
 /// RU: Типы вебхуков. EN: Webhook event types.
-#[derive(Serialize, Debug, Deserialize, Clone, PartialEq)]
+#[derive(Serialize, Debug, Deserialize, Clone, Copy, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum WebhookType {
     IncomingPayment,
@@ -25,21 +27,253 @@ pub enum WebhookType {
     IncomingSbpB2BPayment,
 }
 
+/// RU: Вебхук `acquiringInternetPayment`. EN: `acquiringInternetPayment` webhook claims.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AcquiringClaims {
+    /// RU: Код клиента. EN: Customer code.
     pub customer_code: String,
-    pub amount: String,
+    /// RU: Сумма платежа. EN: Payment amount.
+    pub amount: Money,
+    /// RU: Способ оплаты. EN: Payment method.
     pub payment_type: PaymentMode,
+    /// RU: Тип вебхука. EN: Webhook type.
     pub webhook_type: WebhookType,
+    /// RU: Идентификатор платёжной операции. EN: Payment operation ID.
     pub operation_id: Uuid,
+    /// RU: Назначение платежа. EN: Payment purpose.
     pub purpose: String,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
     pub merchant_id: String,
+    /// RU: Статус операции. EN: Operation status.
     pub status: PaymentStatus,
+    /// RU: Идентификатор покупателя, только для `card`. EN: Consumer ID, `card` payments only.
+    pub consumer_id: Option<Uuid>,
+    /// RU: Идентификатор транзакции, только для `sbp`. EN: Transaction ID, `sbp` payments only.
+    pub transaction_id: Option<Uuid>,
+    /// RU: Идентификатор QR-кода, только для `sbp`. EN: QR code ID, `sbp` payments only.
+    pub qrc_id: Option<String>,
+    /// RU: Имя плательщика, только для `sbp`. EN: Payer name, `sbp` payments only.
+    pub payer_name: Option<String>,
+}
+
+/// RU: Сторона платежа (плательщик или получатель). EN: Payment side (payer or recipient).
+///
+/// В JSON это `amount` и `currency`; валюта переносится в [`Money`], без `currency` — RUB.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(from = "PaymentSideRepr", into = "PaymentSideRepr")]
+pub struct PaymentSide {
+    /// RU: БИК банка. EN: Bank BIC.
+    pub bank_code: String,
+    /// RU: Наименование банка. EN: Bank name.
+    pub bank_name: Option<String>,
+    /// RU: Корсчёт банка. EN: Bank correspondent account.
+    pub bank_corr_account: Option<String>,
+    /// RU: Номер счёта. EN: Account number.
+    pub account: String,
+    /// RU: Наименование. EN: Name.
+    pub name: String,
+    /// RU: Сумма в валюте платежа. EN: Amount in the payment currency.
+    pub amount: Money,
+    /// RU: ИНН. EN: Tax code.
+    pub inn: Option<String>,
+    /// RU: КПП. EN: KPP.
+    pub kpp: Option<String>,
+}
+
+impl PaymentSide {
+    pub fn currency(&self) -> CurrencyCode {
+        self.amount.currency()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaymentSideRepr {
+    bank_code: String,
+    bank_name: Option<String>,
+    bank_corr_account: Option<String>,
+    account: String,
+    name: String,
+    amount: Money,
+    currency: Option<CurrencyCode>,
+    inn: Option<String>,
+    kpp: Option<String>,
+}
+
+impl From<PaymentSideRepr> for PaymentSide {
+    fn from(repr: PaymentSideRepr) -> Self {
+        Self {
+            bank_code: repr.bank_code,
+            bank_name: repr.bank_name,
+            bank_corr_account: repr.bank_corr_account,
+            account: repr.account,
+            name: repr.name,
+            amount: repr
+                .amount
+                .with_currency(repr.currency.unwrap_or(CurrencyCode::RUB)),
+            inn: repr.inn,
+            kpp: repr.kpp,
+        }
+    }
+}
+
+impl From<PaymentSide> for PaymentSideRepr {
+    fn from(side: PaymentSide) -> Self {
+        Self {
+            currency: Some(side.currency()),
+            bank_code: side.bank_code,
+            bank_name: side.bank_name,
+            bank_corr_account: side.bank_corr_account,
+            account: side.account,
+            name: side.name,
+            amount: side.amount,
+            inn: side.inn,
+            kpp: side.kpp,
+        }
+    }
+}
+
+/// RU: Вебхук `incomingPayment` / `outgoingPayment`. EN: Incoming/outgoing payment webhook claims.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentClaims {
+    /// RU: Плательщик. EN: Payer.
+    #[serde(rename = "SidePayer")]
+    pub side_payer: PaymentSide,
+    /// RU: Получатель. EN: Recipient.
+    #[serde(rename = "SideRecipient")]
+    pub side_recipient: PaymentSide,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    pub purpose: String,
+    /// RU: Номер платёжного документа. EN: Payment document number.
+    pub document_number: Option<String>,
+    /// RU: Идентификатор платежа. EN: Payment ID.
+    pub payment_id: String,
+    /// RU: Дата платежа. EN: Payment date.
+    pub date: NaiveDate,
+    /// RU: Тип вебхука. EN: Webhook type.
+    pub webhook_type: WebhookType,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+}
+
+/// RU: Вебхук `incomingSbpPayment`. EN: `incomingSbpPayment` webhook claims.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpPaymentClaims {
+    /// RU: Идентификатор операции СБП. EN: SBP operation ID.
+    pub operation_id: String,
+    /// RU: Идентификатор QR-кода. EN: QR code ID.
+    pub qrc_id: String,
+    /// RU: Сумма платежа. EN: Payment amount.
+    pub amount: Money,
+    /// RU: Телефон плательщика. EN: Payer phone number.
+    pub payer_mobile_number: Option<String>,
+    /// RU: Имя плательщика. EN: Payer name.
+    pub payer_name: Option<String>,
+    /// RU: Бренд ТСП. EN: Merchant brand name.
+    pub brand_name: Option<String>,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: String,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    pub purpose: Option<String>,
+    /// RU: Тип вебхука. EN: Webhook type.
+    pub webhook_type: WebhookType,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+    /// RU: Идентификатор операции, к которой относится возврат. EN: Refunded operation ID.
+    pub ref_transaction_id: Option<String>,
+}
+
+/// RU: Вебхук `incomingSbpB2BPayment`. EN: `incomingSbpB2BPayment` webhook claims.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SbpB2BPaymentClaims {
+    /// RU: Идентификатор операции СБП. EN: SBP operation ID.
+    pub operation_id: String,
+    /// RU: Идентификатор QR-кода. EN: QR code ID.
+    pub qrc_id: String,
+    /// RU: Сумма платежа. EN: Payment amount.
+    pub amount: Money,
+    /// RU: Назначение платежа. EN: Payment purpose.
+    pub purpose: Option<String>,
+    /// RU: Идентификатор ТСП. EN: Merchant ID.
+    pub merchant_id: String,
+    /// RU: Тип вебхука. EN: Webhook type.
+    pub webhook_type: WebhookType,
+    /// RU: Код клиента. EN: Customer code.
+    pub customer_code: String,
+    /// RU: Юрлицо-плательщик. EN: Paying legal entity.
+    #[serde(rename = "SidePayer")]
+    pub side_payer: Option<PaymentSide>,
+}
+
+/// RU: Вебхук любого типа, выбирается по полю `webhookType`.
+/// EN: Webhook of any type, dispatched on the `webhookType` claim.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum WebhookEvent {
+    IncomingPayment(PaymentClaims),
+    OutgoingPayment(PaymentClaims),
+    IncomingSbpPayment(SbpPaymentClaims),
+    AcquiringInternetPayment(AcquiringClaims),
+    IncomingSbpB2BPayment(SbpB2BPaymentClaims),
+}
+
+impl WebhookEvent {
+    /// RU: Тип вебхука. EN: Webhook type.
+    pub fn webhook_type(&self) -> WebhookType {
+        match self {
+            Self::IncomingPayment(claims) | Self::OutgoingPayment(claims) => claims.webhook_type,
+            Self::IncomingSbpPayment(claims) => claims.webhook_type,
+            Self::AcquiringInternetPayment(claims) => claims.webhook_type,
+            Self::IncomingSbpB2BPayment(claims) => claims.webhook_type,
+        }
+    }
+
+    /// RU: Код клиента, которому пришёл вебхук. EN: Customer code the webhook belongs to.
+    pub fn customer_code(&self) -> &str {
+        match self {
+            Self::IncomingPayment(claims) | Self::OutgoingPayment(claims) => &claims.customer_code,
+            Self::IncomingSbpPayment(claims) => &claims.customer_code,
+            Self::AcquiringInternetPayment(claims) => &claims.customer_code,
+            Self::IncomingSbpB2BPayment(claims) => &claims.customer_code,
+        }
+    }
+}
+
+// `#[serde(tag = "webhookType")]` съел бы поле, которое есть в каждой структуре,
+// поэтому тип читаем отдельно и разбираем полезную нагрузку целиком.
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let webhook_type = value
+            .get("webhookType")
+            .cloned()
+            .ok_or_else(|| D::Error::missing_field("webhookType"))?;
+        let webhook_type = WebhookType::deserialize(webhook_type).map_err(D::Error::custom)?;
 
-    // optional fields depending on paymentType
-    pub consumer_id: Option<Uuid>,    // present for `card`
-    pub transaction_id: Option<Uuid>, // present for `sbp`
-    pub qrc_id: Option<String>,       // sbp only
-    pub payer_name: Option<String>,   // sbp only
+        match webhook_type {
+            WebhookType::IncomingPayment => {
+                serde_json::from_value(value).map(Self::IncomingPayment)
+            }
+            WebhookType::OutgoingPayment => {
+                serde_json::from_value(value).map(Self::OutgoingPayment)
+            }
+            WebhookType::IncomingSbpPayment => {
+                serde_json::from_value(value).map(Self::IncomingSbpPayment)
+            }
+            WebhookType::AcquiringInternetPayment => {
+                serde_json::from_value(value).map(Self::AcquiringInternetPayment)
+            }
+            WebhookType::IncomingSbpB2BPayment => {
+                serde_json::from_value(value).map(Self::IncomingSbpB2BPayment)
+            }
+        }
+        .map_err(D::Error::custom)
+    }
 }
@@ -1,7 +1,8 @@
 use chrono::DateTime;
 use codes_iso_4217::CurrencyCode;
+use rust_decimal_macros::dec;
 use tochka_sdk::{
-    BalancePageData, BalanceType, CreditDebitIndicator, Data, PaginatedResponse,
+    BalancePageData, BalanceType, CreditDebitIndicator, Data, Money, PaginatedResponse,
     TransactionPageData,
 };
 
@@ -49,9 +50,9 @@ fn deserialize_authorized_card_transactions_example() {
     assert_eq!(tx.account_id, "40817810802000000008/044525104");
     assert_eq!(tx.pan, "string");
     assert_eq!(tx.date_time, expected_date);
-    assert_eq!(tx.amount.amount, 1234.56);
-    assert_eq!(tx.amount.currency, CurrencyCode::RUB);
-    assert_eq!(tx.account_amount.currency, CurrencyCode::RUB);
+    assert_eq!(tx.amount.amount, Money::rub(dec!(1234.56)));
+    assert_eq!(tx.amount.currency(), CurrencyCode::RUB);
+    assert_eq!(tx.account_amount.currency(), CurrencyCode::RUB);
     assert_eq!(tx.terminal_data.city.as_deref(), Some("Perm"));
     assert_eq!(tx.terminal_data.owner.as_deref(), Some("string"));
 }
@@ -89,8 +90,8 @@ fn deserialize_balance_info_example() {
     assert_eq!(balance.account_id, "40817810802000000008/044525104");
     assert_eq!(balance.credit_debit_indicator, CreditDebitIndicator::Credit);
     assert_eq!(balance.balance_type, BalanceType::ClosingAvailable);
-    assert_eq!(balance.amount.amount, 1234.56);
-    assert_eq!(balance.amount.currency, CurrencyCode::RUB);
+    assert_eq!(balance.amount.amount, Money::rub(dec!(1234.56)));
+    assert_eq!(balance.amount.currency(), CurrencyCode::RUB);
 }
 
 #[test]
@@ -145,5 +146,5 @@ fn deserialize_balances_list_example() {
         CreditDebitIndicator::Debit
     );
     assert_eq!(parsed.data.balance[1].balance_type, BalanceType::Expected);
-    assert_eq!(parsed.data.balance[1].amount.currency, CurrencyCode::RUB);
+    assert_eq!(parsed.data.balance[1].amount.currency(), CurrencyCode::RUB);
 }
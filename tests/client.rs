@@ -0,0 +1,47 @@
+use tochka_sdk::{ApiVersion, Client, Environment, Error, Service, testing::mock_jwk};
+
+#[tokio::test]
+async fn builder_uses_base_url_override() {
+    let client = Client::builder()
+        .environment(Environment::Production)
+        .token("token")
+        .base_url("http://127.0.0.1:8080/")
+        .customer_code("300000092")
+        .jwk(mock_jwk())
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(client.base_url(), "http://127.0.0.1:8080/");
+    assert_eq!(client.customer_code.as_deref(), Some("300000092"));
+    assert_eq!(
+        client.url(Service::OpenBanking, ApiVersion::V1_0, "/accounts"),
+        "http://127.0.0.1:8080/open-banking/v1.0/accounts"
+    );
+}
+
+#[tokio::test]
+async fn builder_defaults_to_environment_base_url() {
+    let client = Client::builder()
+        .environment(Environment::Sandbox)
+        .jwk(mock_jwk())
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client.url(Service::Acquiring, ApiVersion::V1_0, "payments"),
+        "https://enter.tochka.com/sandbox/v2/acquiring/v1.0/payments"
+    );
+}
+
+#[tokio::test]
+async fn builder_requires_token_in_production() {
+    let result = Client::builder()
+        .environment(Environment::Production)
+        .jwk(mock_jwk())
+        .build()
+        .await;
+
+    assert!(matches!(result, Err(Error::Config(_))));
+}
@@ -0,0 +1,28 @@
+//! Общий HTTP-сервер для тестов, которым нужны свои обработчики вместо `MockServer`.
+#![allow(dead_code)]
+
+use axum::Router;
+use std::net::SocketAddr;
+use tochka_sdk::{Client, ClientBuilder, Environment, testing::mock_jwk};
+
+/// Поднять `app` на свободном порту `127.0.0.1`.
+pub async fn listen(app: Router) -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    addr
+}
+
+/// Билдер клиента песочницы, который ходит на `addr`.
+pub fn client_builder(addr: SocketAddr) -> ClientBuilder {
+    Client::builder()
+        .environment(Environment::Sandbox)
+        .base_url(format!("http://{addr}"))
+        .jwk(mock_jwk())
+}
+
+/// Поднять `app` и собрать клиента к нему.
+pub async fn serve(app: Router) -> (Client, SocketAddr) {
+    let addr = listen(app).await;
+    (client_builder(addr).build().await.unwrap(), addr)
+}
@@ -0,0 +1,83 @@
+use tochka_sdk::{ConsentPageData, ConsentPayload, ConsentStatus, Data, ExternalConsentTypeEnum};
+
+const CONSENTS: &str = r#"
+{
+  "Data": {
+    "Consent": [
+      {
+        "status": "Authorised",
+        "consentId": "0b7cdb4c-6a7a-4a9c-b2b6-8c0b5fa2f7b1",
+        "customerCode": "300000092",
+        "applicationName": "Accounting",
+        "clientId": "2951813b",
+        "permissions": [
+          "ReadAccountsBasic",
+          "ReadBalances",
+          "ReadStatements"
+        ],
+        "expirationDateTime": "2099-01-01T00:00:00+00:00",
+        "creationDateTime": "2024-01-01T00:00:00+00:00",
+        "statusUpdateDateTime": "2024-01-01T00:00:00+00:00",
+        "isValid": true
+      },
+      {
+        "status": "Revoked",
+        "consentId": "7c3c0f2c-1d6f-43c7-a0f4-3b8d2a1f0e55",
+        "permissions": ["ReadSBPData"],
+        "expirationDateTime": null,
+        "creationDateTime": "2023-01-01T00:00:00+00:00",
+        "isValid": false
+      }
+    ]
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/consent/v1.0/consents"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+"#;
+
+#[test]
+fn deserialize_consents_list() {
+    let parsed: Data<ConsentPageData> = serde_json::from_str(CONSENTS).unwrap();
+    let consent = &parsed.data.consent[0];
+
+    assert_eq!(consent.status, ConsentStatus::Authorised);
+    assert_eq!(consent.customer_code.as_deref(), Some("300000092"));
+    assert_eq!(consent.permissions.len(), 3);
+    assert_eq!(
+        parsed.data.consent[1].permissions,
+        vec![ExternalConsentTypeEnum::ReadSbpData]
+    );
+}
+
+#[test]
+fn consent_covers_granted_permissions_only() {
+    let parsed: Data<ConsentPageData> = serde_json::from_str(CONSENTS).unwrap();
+    let active = &parsed.data.consent[0];
+    let revoked = &parsed.data.consent[1];
+
+    assert!(active.covers(&[ExternalConsentTypeEnum::ReadStatements]));
+    assert_eq!(
+        active.missing(&[
+            ExternalConsentTypeEnum::ReadBalances,
+            ExternalConsentTypeEnum::CreatePaymentOrder
+        ]),
+        vec![ExternalConsentTypeEnum::CreatePaymentOrder]
+    );
+    assert!(!revoked.covers(&[ExternalConsentTypeEnum::ReadSbpData]));
+}
+
+#[test]
+fn serialize_consent_payload() {
+    let payload = ConsentPayload::new([
+        ExternalConsentTypeEnum::ReadAccountsBasic,
+        ExternalConsentTypeEnum::ReadSbpData,
+    ]);
+    let json = serde_json::to_value(payload).unwrap();
+
+    assert_eq!(json["permissions"][1], "ReadSBPData");
+    assert!(json.get("expirationDateTime").is_none());
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use axum::{
+    Router,
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use tochka_sdk::{ApiError, Client, Error, REQUEST_ID_HEADER};
+
+const FORBIDDEN: &str = r#"
+{
+  "code": "403",
+  "id": "a2f4c3b1-7e4d-4c4f-9d0e-5f3c2b1a0d9e",
+  "message": "Доступ запрещён",
+  "Errors": [
+    {
+      "errorCode": "Consent.InsufficientPermissions",
+      "message": "Нет разрешения ReadStatements",
+      "url": "https://developers.tochka.com/docs/tochka-api/opisanie-metodov/kody-oshibok"
+    }
+  ]
+}
+"#;
+
+async fn client() -> Client {
+    let app = Router::new()
+        .route(
+            "/open-banking/v1.0/customers/{code}",
+            get(|| async {
+                (
+                    StatusCode::FORBIDDEN,
+                    [
+                        (header::CONTENT_TYPE, "application/json"),
+                        (header::HeaderName::from_static(REQUEST_ID_HEADER), "req-42"),
+                    ],
+                    FORBIDDEN,
+                )
+                    .into_response()
+            }),
+        )
+        .route(
+            "/open-banking/v1.0/accounts",
+            get(|| async { (StatusCode::NOT_FOUND, "no such route").into_response() }),
+        );
+    common::serve(app).await.0
+}
+
+#[tokio::test]
+async fn error_envelope_is_parsed() {
+    let client = client().await;
+
+    let err = client.get_customer_info("300000092").await.unwrap_err();
+
+    let Error::Forbidden(api) = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(api.status, 403);
+    assert_eq!(api.request_id.as_deref(), Some("req-42"));
+    assert_eq!(api.code.as_deref(), Some("403"));
+    assert_eq!(api.message.as_deref(), Some("Доступ запрещён"));
+    assert!(api.has_error_code("Consent.InsufficientPermissions"));
+    assert_eq!(err.api_error(), Some(api.as_ref()));
+}
+
+#[tokio::test]
+async fn non_json_error_keeps_status_and_raw_body() {
+    let client = client().await;
+
+    let err = client.get_accounts_list().await.unwrap_err();
+
+    let api = err.api_error().expect("API error");
+    assert!(matches!(err, Error::NotFound(_)));
+    assert_eq!(api.status, 404);
+    assert_eq!(api.raw, "no such route");
+    assert!(api.errors.is_empty());
+}
+
+#[test]
+fn numeric_error_code_is_parsed() {
+    let api = ApiError::from_response(
+        reqwest::StatusCode::FORBIDDEN,
+        &reqwest::header::HeaderMap::new(),
+        FORBIDDEN.replace(r#""code": "403""#, r#""code": 403"#),
+    );
+
+    assert_eq!(api.code.as_deref(), Some("403"));
+    assert_eq!(api.message.as_deref(), Some("Доступ запрещён"));
+    assert!(api.has_error_code("Consent.InsufficientPermissions"));
+}
+
+#[test]
+fn request_id_falls_back_to_body_id() {
+    let api = ApiError::from_response(
+        reqwest::StatusCode::BAD_REQUEST,
+        &reqwest::header::HeaderMap::new(),
+        FORBIDDEN.into(),
+    );
+
+    assert_eq!(
+        api.request_id.as_deref(),
+        Some("a2f4c3b1-7e4d-4c4f-9d0e-5f3c2b1a0d9e")
+    );
+    assert_eq!(
+        api.error_codes().collect::<Vec<_>>(),
+        ["Consent.InsufficientPermissions"]
+    );
+}
@@ -0,0 +1,286 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use std::{sync::Arc, time::Duration};
+use tochka_sdk::{
+    Client, CreatePaymentPayload, Error, IdempotencyKey, IdempotencyStore,
+    InMemoryIdempotencyStore, MOCK_CUSTOMER_CODE, MockServer, Money, PaymentPath, PaymentStatus,
+    RefundPayload, RetryPolicy, StoredResponse,
+};
+use uuid::Uuid;
+
+/// Хранилище, которое не может ничего записать.
+#[derive(Debug)]
+struct BrokenStore;
+
+#[async_trait]
+impl IdempotencyStore for BrokenStore {
+    async fn get(&self, _key: &IdempotencyKey) -> Result<Option<StoredResponse>, Error> {
+        Ok(None)
+    }
+
+    async fn put(&self, _key: &IdempotencyKey, _response: StoredResponse) -> Result<(), Error> {
+        Err(Error::Network("store is down".into()))
+    }
+}
+
+async fn setup(server: &MockServer, store: Option<Arc<dyn IdempotencyStore>>) -> Client {
+    let mut builder = server
+        .client_builder()
+        .retry_policy(RetryPolicy::default().base_delay(Duration::from_millis(1)));
+    if let Some(store) = store {
+        builder = builder.idempotency_store(store);
+    }
+    builder.build().await.unwrap()
+}
+
+/// Оплаченная операция, по которой можно делать возвраты.
+async fn approved_payment(server: &MockServer) -> Uuid {
+    let client = server.client().await.unwrap();
+    let operation = client
+        .create_payment_operation(payment(), PaymentPath::Standard)
+        .await
+        .unwrap()
+        .data;
+    server
+        .complete_payment(operation.operation_id, PaymentStatus::Approved)
+        .await
+        .unwrap();
+    operation.operation_id
+}
+
+/// Ключи идемпотентности запросов, пришедших на `path`.
+fn keys(server: &MockServer, path: &str) -> Vec<Option<String>> {
+    server
+        .requests()
+        .into_iter()
+        .filter(|request| request.path == path)
+        .map(|request| request.idempotency_key)
+        .collect()
+}
+
+fn refund_path(operation_id: Uuid) -> String {
+    format!("/acquiring/v1.0/payments/{operation_id}/refund")
+}
+
+const PAYMENTS_PATH: &str = "/acquiring/v1.0/payments";
+
+fn payment() -> CreatePaymentPayload {
+    CreatePaymentPayload::new(
+        Money::rub(dec!(100)),
+        Some(MOCK_CUSTOMER_CODE.into()),
+        "Заказ",
+    )
+}
+
+fn refund_payload() -> RefundPayload {
+    RefundPayload {
+        amount: Money::rub(dec!(40)),
+    }
+}
+
+#[tokio::test]
+async fn payment_creation_without_key_is_not_retried() {
+    let server = MockServer::start().await;
+    let client = setup(&server, None).await;
+    server.fail_next(StatusCode::BAD_GATEWAY, 1);
+
+    let err = client
+        .create_payment_operation(payment(), PaymentPath::Standard)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Server(_)));
+    assert_eq!(keys(&server, PAYMENTS_PATH), vec![None]);
+    assert!(server.payments().is_empty());
+}
+
+#[tokio::test]
+async fn payment_creation_with_key_is_retried_with_the_same_key() {
+    let server = MockServer::start().await;
+    let client = setup(&server, None).await;
+    server.fail_next(StatusCode::BAD_GATEWAY, 1);
+
+    let operation = client
+        .create_payment_operation_with_key(payment(), PaymentPath::Standard, "order-42")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        keys(&server, PAYMENTS_PATH),
+        vec![Some("order-42".to_string()), Some("order-42".to_string())]
+    );
+    assert_eq!(operation.data.amount, Money::rub(dec!(100)));
+    assert_eq!(server.payments().len(), 1);
+}
+
+#[tokio::test]
+async fn refund_without_key_is_sent_once_on_503() {
+    let server = MockServer::start().await;
+    let client = setup(&server, None).await;
+    let operation_id = approved_payment(&server).await;
+    server.fail_next(StatusCode::SERVICE_UNAVAILABLE, 3);
+
+    let err = client
+        .refund_payment_operation(operation_id.to_string(), refund_payload())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Server(_)));
+    assert_eq!(keys(&server, &refund_path(operation_id)), vec![None]);
+}
+
+#[tokio::test]
+async fn user_supplied_key_is_sent() {
+    let server = MockServer::start().await;
+    let client = setup(&server, None).await;
+    let operation_id = approved_payment(&server).await;
+
+    client
+        .refund_payment_operation_with_key(
+            operation_id.to_string(),
+            refund_payload(),
+            "order-42-refund-1",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        keys(&server, &refund_path(operation_id)),
+        vec![Some("order-42-refund-1".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn store_replays_the_original_result() {
+    let server = MockServer::start().await;
+    let store = Arc::new(InMemoryIdempotencyStore::new());
+    let client = setup(&server, Some(store.clone())).await;
+    let operation_id = approved_payment(&server).await.to_string();
+    let key = IdempotencyKey::new();
+
+    let first = client
+        .refund_payment_operation_with_key(&operation_id, refund_payload(), key.clone())
+        .await
+        .unwrap();
+    let replayed = client
+        .refund_payment_operation_with_key(&operation_id, refund_payload(), key.clone())
+        .await
+        .unwrap();
+    let other = client
+        .refund_payment_operation_with_key(&operation_id, refund_payload(), IdempotencyKey::new())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        keys(&server, &refund_path(first.data.operation_id)).len(),
+        2
+    );
+    assert_eq!(replayed.data.order_id, first.data.order_id);
+    assert_ne!(other.data.order_id, first.data.order_id);
+    assert_eq!(store.len(), 2);
+}
+
+#[tokio::test]
+async fn reused_key_with_another_payload_is_rejected() {
+    let server = MockServer::start().await;
+    let store = Arc::new(InMemoryIdempotencyStore::new());
+    let client = setup(&server, Some(store)).await;
+    let operation_id = approved_payment(&server).await;
+    let key = IdempotencyKey::from("order-42-refund");
+
+    client
+        .refund_payment_operation_with_key(operation_id.to_string(), refund_payload(), key.clone())
+        .await
+        .unwrap();
+    let other_amount = RefundPayload {
+        amount: Money::rub(dec!(41)),
+    };
+    let err = client
+        .refund_payment_operation_with_key(operation_id.to_string(), other_amount, key.clone())
+        .await
+        .unwrap_err();
+    let other_operation = client
+        .refund_payment_operation_with_key(Uuid::from_u128(42).to_string(), refund_payload(), key)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::IdempotencyKeyReused { key } if key == "order-42-refund"));
+    assert!(matches!(
+        other_operation,
+        Error::IdempotencyKeyReused { .. }
+    ));
+    assert_eq!(keys(&server, &refund_path(operation_id)).len(), 1);
+}
+
+#[tokio::test]
+async fn store_failure_does_not_lose_the_response() {
+    let server = MockServer::start().await;
+    let client = setup(&server, Some(Arc::new(BrokenStore))).await;
+    let operation_id = approved_payment(&server).await;
+
+    let refund = client
+        .refund_payment_operation_with_key(
+            operation_id.to_string(),
+            refund_payload(),
+            "order-1-refund",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(refund.data.amount, Money::rub(dec!(40)));
+    assert_eq!(keys(&server, &refund_path(operation_id)).len(), 1);
+}
+
+#[tokio::test]
+async fn failed_requests_are_not_stored() {
+    let server = MockServer::start().await;
+    let store = Arc::new(InMemoryIdempotencyStore::new());
+    let key = IdempotencyKey::from("order-7");
+
+    // Без повторов первая попытка падает, а вторая с тем же ключом доходит до сервера.
+    let no_retry = server
+        .client_builder()
+        .retry_policy(RetryPolicy::none())
+        .idempotency_store(store.clone())
+        .build()
+        .await
+        .unwrap();
+    server.fail_next(StatusCode::BAD_GATEWAY, 1);
+    assert!(
+        no_retry
+            .create_payment_operation_with_key(payment(), PaymentPath::Standard, key.clone())
+            .await
+            .is_err()
+    );
+    assert!(store.is_empty());
+
+    let operation = no_retry
+        .create_payment_operation_with_key(payment(), PaymentPath::Standard, key.clone())
+        .await
+        .unwrap();
+    assert_eq!(keys(&server, PAYMENTS_PATH).len(), 2);
+    assert_eq!(store.len(), 1);
+    assert_eq!(
+        server.payments()[0].operation_id,
+        operation.data.operation_id
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn in_memory_store_expires_entries() {
+    let store = InMemoryIdempotencyStore::new().ttl(Duration::from_secs(60));
+    let key = IdempotencyKey::from("k");
+
+    let response = StoredResponse {
+        fingerprint: "f".into(),
+        body: b"{}".to_vec(),
+    };
+
+    store.put(&key, response.clone()).await.unwrap();
+    assert_eq!(store.get(&key).await.unwrap(), Some(response));
+
+    tokio::time::advance(Duration::from_secs(61)).await;
+    assert_eq!(store.get(&key).await.unwrap(), None);
+    assert!(store.is_empty());
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use axum::{Router, routing::get};
+use chrono::NaiveDate;
+use codes_iso_4217::CurrencyCode;
+use rust_decimal_macros::dec;
+use tochka_sdk::{
+    BillPayload, ClosingDocumentContent, ClosingDocumentPayload, Data, InvoiceDocument,
+    InvoicePaymentStatus, InvoicePaymentStatusInfo, InvoicePosition, Money, SecondSide,
+    SecondSideType, Supplier, UnitCode, VatType,
+};
+use validator::Validate;
+
+fn bill() -> BillPayload {
+    let invoice = InvoiceDocument::new(
+        "42",
+        vec![
+            InvoicePosition::new(
+                "Консультация",
+                UnitCode::Hour,
+                VatType::Vat20,
+                Money::rub(dec!(1500)),
+                dec!(2),
+            ),
+            InvoicePosition::new(
+                "Отчёт",
+                UnitCode::Pieces,
+                VatType::None,
+                Money::rub(dec!(500)),
+                dec!(1),
+            ),
+        ],
+    )
+    .date(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap())
+    .payment_expiry_date(NaiveDate::from_ymd_opt(2025, 4, 15).unwrap());
+
+    BillPayload::new(
+        "40702810840020002503/044525104",
+        "300000092",
+        SecondSide::new("7701234567", SecondSideType::Company).name("ООО Ромашка"),
+        invoice,
+    )
+}
+
+#[test]
+fn serialize_bill_payload() {
+    let payload = bill();
+    let json = serde_json::to_value(&payload).unwrap();
+    let invoice = &json["Content"]["Invoice"];
+
+    assert!(payload.validate().is_ok());
+    assert_eq!(json["SecondSide"]["type"], "company");
+    assert_eq!(invoice["totalAmount"], 3500.0);
+    assert_eq!(invoice["totalNds"], 500.0);
+    assert_eq!(invoice["Positions"][0]["unitCode"], "ч.");
+    assert_eq!(invoice["Positions"][0]["ndsKind"], "nds_20");
+    assert_eq!(invoice["Positions"][1]["ndsKind"], "without_nds");
+}
+
+#[test]
+fn invoice_position_uses_receipt_vat_and_supplier() {
+    let supplier = Supplier {
+        phone: "+79001234567".into(),
+        name: "ИП Иванов".into(),
+        tax_code: "770123456789".into(),
+    };
+    let position = InvoicePosition::new(
+        "Доставка",
+        UnitCode::Pieces,
+        VatType::Vat120,
+        Money::rub(dec!(1200)),
+        dec!(1),
+    )
+    .supplier(supplier.clone());
+    let json = serde_json::to_value(&position).unwrap();
+
+    assert!(position.validate().is_ok());
+    assert_eq!(json["ndsKind"], "nds_20");
+    assert_eq!(json["totalNds"], 200.0);
+    assert_eq!(json["Supplier"]["taxCode"], "770123456789");
+    let parsed: InvoicePosition = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.nds_kind, VatType::Vat20);
+    assert_eq!(parsed.supplier, Some(supplier));
+    assert!(
+        serde_json::to_value(InvoicePosition::new(
+            "Отчёт",
+            UnitCode::Pieces,
+            VatType::None,
+            Money::rub(dec!(1)),
+            dec!(1),
+        ))
+        .unwrap()
+        .get("Supplier")
+        .is_none()
+    );
+}
+
+#[test]
+fn serialize_closing_document_payload() {
+    let act = InvoiceDocument::new(
+        "7",
+        vec![InvoicePosition::new(
+            "Консультация",
+            UnitCode::Hour,
+            VatType::Vat0,
+            Money::rub(dec!(1500)),
+            dec!(2),
+        )],
+    );
+    let payload = ClosingDocumentPayload::new(
+        "40702810840020002503/044525104",
+        "300000092",
+        SecondSide::new("7701234567", SecondSideType::Ip),
+        ClosingDocumentContent::Act(act),
+    )
+    .document_id("bill-1");
+    let json = serde_json::to_value(payload).unwrap();
+
+    assert_eq!(json["documentId"], "bill-1");
+    assert_eq!(json["Content"]["Act"]["totalAmount"], 3000.0);
+}
+
+#[test]
+fn bill_without_positions_fails_validation() {
+    let mut payload = bill();
+    payload.content.invoice.positions.clear();
+
+    assert!(payload.validate().is_err());
+}
+
+#[test]
+fn positions_in_different_currencies_fail_validation() {
+    let mut payload = bill();
+    let position = &mut payload.content.invoice.positions[1];
+    position.price = position.price.with_currency(CurrencyCode::USD);
+    position.total_amount = position.total_amount.with_currency(CurrencyCode::USD);
+    let invoice = InvoiceDocument::new("43", payload.content.invoice.positions.clone());
+
+    let errors = payload.validate().unwrap_err().to_string();
+    assert!(errors.contains("positions_currency"), "{errors}");
+    assert!(invoice.total_amount.is_zero());
+    assert!(invoice.validate().is_err());
+}
+
+#[test]
+fn deserialize_bill_payment_status() {
+    let json = r#"
+{
+  "Data": {
+    "paymentStatus": "payment_paid"
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/invoice/v1.0/bills/300000092/doc-1/payment-status"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<InvoicePaymentStatusInfo> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        parsed.data.payment_status,
+        InvoicePaymentStatus::PaymentPaid
+    );
+}
+
+#[tokio::test]
+async fn downloads_bill_pdf_as_bytes() {
+    let app = Router::new().route(
+        "/invoice/v1.0/bills/300000092/doc-1/file",
+        get(|| async { b"%PDF-1.4 stub".to_vec() }),
+    );
+    let (client, _) = common::serve(app).await;
+
+    let pdf = client.get_bill_file("300000092", "doc-1").await.unwrap();
+
+    assert!(pdf.starts_with(b"%PDF"));
+}
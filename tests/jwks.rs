@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tochka_sdk::{
+    Client, Environment, Error, Jwk, JwkCache, JwkSet, JwkSource, StaticJwkSource, WebhookType,
+    testing::{mock_jwk, sign_mock_webhook_with_kid},
+};
+
+fn retired_key() -> Jwk {
+    Jwk {
+        kty: "RSA".into(),
+        n: "stub".into(),
+        e: "AQAB".into(),
+        kid: Some("retired".into()),
+        alg: Some("RS256".into()),
+    }
+}
+
+fn sign(kid: Option<&str>) -> String {
+    let claims = serde_json::json!({
+        "operationId": "A41580807342410000000011400201",
+        "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "amount": "250.00",
+        "merchantId": "MA0000000552",
+        "webhookType": "incomingSbpPayment",
+        "customerCode": "300000092"
+    });
+    sign_mock_webhook_with_kid(kid, &claims)
+}
+
+/// Отдаёт старый ключ при первой загрузке и новый — при всех последующих.
+#[derive(Debug, Clone, Default)]
+struct RotatingSource {
+    fetches: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl JwkSource for RotatingSource {
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        let key = match self.fetches.fetch_add(1, Ordering::SeqCst) {
+            0 => retired_key(),
+            _ => mock_jwk(),
+        };
+        Ok(key.into())
+    }
+}
+
+/// Отдаёт ключ при первой загрузке и падает при всех последующих.
+#[derive(Debug, Clone, Default)]
+struct FailingSource {
+    fetches: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl JwkSource for FailingSource {
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        match self.fetches.fetch_add(1, Ordering::SeqCst) {
+            0 => Ok(mock_jwk().into()),
+            _ => Err(Error::Network("keys endpoint is down".into())),
+        }
+    }
+}
+
+async fn client(cache: JwkCache) -> Client {
+    Client::builder()
+        .environment(Environment::Sandbox)
+        .jwk_cache(cache)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[test]
+fn jwk_set_accepts_single_key_and_key_set() {
+    let key = mock_jwk();
+    let key = serde_json::json!({
+        "kty": key.kty, "n": key.n, "e": key.e, "kid": key.kid, "alg": key.alg
+    });
+    let single: JwkSet = serde_json::from_value(key.clone()).unwrap();
+    let set: JwkSet = serde_json::from_value(serde_json::json!({ "keys": [key, key] })).unwrap();
+
+    assert_eq!(single.keys.len(), 1);
+    assert_eq!(set.keys.len(), 2);
+    assert_eq!(set.find(Some("test")).len(), 2);
+    assert!(set.find(Some("other")).is_empty());
+}
+
+#[tokio::test]
+async fn selects_key_by_kid() {
+    let keys = JwkSet {
+        keys: vec![retired_key(), mock_jwk()],
+    };
+    let client = client(JwkCache::new(StaticJwkSource(keys))).await;
+
+    let with_kid = client.decode_webhook(&sign(Some("test"))).await.unwrap();
+    let without_kid = client.decode_webhook(&sign(None)).await.unwrap();
+
+    assert_eq!(with_kid.webhook_type(), WebhookType::IncomingSbpPayment);
+    assert_eq!(without_kid, with_kid);
+}
+
+#[tokio::test]
+async fn refreshes_on_unknown_kid() {
+    let source = RotatingSource::default();
+    let fetches = source.fetches.clone();
+    let client = client(JwkCache::new(source).refresh_interval(Duration::ZERO)).await;
+
+    let event = client.decode_webhook(&sign(Some("test"))).await.unwrap();
+
+    assert_eq!(event.customer_code(), "300000092");
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn unknown_kid_refresh_is_rate_limited() {
+    let source = RotatingSource::default();
+    let fetches = source.fetches.clone();
+    let client = client(JwkCache::new(source).refresh_interval(Duration::from_secs(3600))).await;
+
+    assert!(matches!(
+        client.decode_webhook(&sign(Some("test"))).await,
+        Err(Error::InvalidToken(_))
+    ));
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn reloads_keys_after_ttl() {
+    let source = RotatingSource::default();
+    let fetches = source.fetches.clone();
+    let client = client(
+        JwkCache::new(source)
+            .ttl(Duration::ZERO)
+            .refresh_interval(Duration::from_secs(3600)),
+    )
+    .await;
+
+    assert!(client.jwks().is_stale());
+    client.decode_webhook(&sign(None)).await.unwrap();
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    assert_eq!(client.jwks().keys().keys[0].kid.as_deref(), Some("test"));
+}
+
+#[tokio::test]
+async fn failed_reload_is_not_retried_before_refresh_interval() {
+    let source = FailingSource::default();
+    let fetches = source.fetches.clone();
+    let client = client(
+        JwkCache::new(source)
+            .ttl(Duration::ZERO)
+            .refresh_interval(Duration::from_secs(3600)),
+    )
+    .await;
+
+    for _ in 0..3 {
+        client.decode_webhook(&sign(Some("test"))).await.unwrap();
+    }
+    assert!(matches!(
+        client.decode_webhook(&sign(Some("retired"))).await,
+        Err(Error::InvalidToken(_))
+    ));
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
@@ -11,7 +11,7 @@ async fn decode_acquiring_payment_webhook_jwt() {
         decoded.claims,
         AcquiringClaims {
             customer_code: "300123123".into(),
-            amount: "0.33".into(),
+            amount: "0.33".parse().unwrap(),
             payment_type: PaymentMode::Card,
             operation_id: uuid!("beeac8a4-6047-3f38-8922-a664e6b5c43b"),
             purpose: "Оплата по счету № 1 от 01.01.2021. Без НДС".into(),
@@ -0,0 +1,126 @@
+use codes_iso_4217::CurrencyCode;
+use rust_decimal_macros::dec;
+use tochka_sdk::{Amount, Money, QrCodePayload, ReceiptItem, RefundPayload};
+use validator::Validate;
+
+#[test]
+fn deserialize_from_number_and_string() {
+    let numbers: Vec<Money> = serde_json::from_str(r#"[1500.5, 42, "0.33", "18548,39"]"#).unwrap();
+
+    assert_eq!(
+        numbers,
+        [
+            Money::rub(dec!(1500.5)),
+            Money::rub(dec!(42)),
+            Money::rub(dec!(0.33)),
+            Money::rub(dec!(18548.39)),
+        ]
+    );
+    assert!(serde_json::from_str::<Money>(r#""abc""#).is_err());
+}
+
+#[test]
+fn serialize_as_bare_number() {
+    let json = serde_json::to_string(&Money::rub(dec!(1500.50))).unwrap();
+
+    assert_eq!(json, "1500.5");
+}
+
+#[test]
+fn large_amounts_keep_precision() {
+    // 2^53 копеек и больше в f64 уже не помещаются точно.
+    let money = Money::rub(dec!(123456789012345678.91));
+
+    let json = serde_json::to_string(&money).unwrap();
+
+    assert_eq!(json, "123456789012345678.91");
+    assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+    assert_eq!(
+        serde_json::from_slice::<Vec<Money>>(
+            br#"[123456789012345678.91, "123456789012345678.91"]"#
+        )
+        .unwrap(),
+        [money, money]
+    );
+}
+
+#[test]
+fn kopeck_overflow_is_a_serialization_error() {
+    let money = Money::rub(dec!(100000000000000000000));
+    assert_eq!(money.checked_kopecks(), None);
+
+    let payload = QrCodePayload::dynamic_qr(money, "Оплата");
+
+    assert!(serde_json::to_string(&payload).is_err());
+}
+
+#[test]
+fn quantity_must_be_positive() {
+    let item = |quantity| ReceiptItem::new("Кофе", Money::rub(dec!(100)), quantity);
+
+    assert!(item(dec!(0.001)).validate().is_ok());
+    let errors = item(dec!(0)).validate().unwrap_err();
+    assert_eq!(
+        errors.field_errors()["quantity"][0].code,
+        "quantity_positive"
+    );
+    assert!(item(dec!(-1)).validate().is_err());
+    assert_eq!(item(dec!(1.5)).total(), Money::rub(dec!(150)));
+}
+
+#[test]
+fn amounts_are_exact_and_rounded_to_kopecks() {
+    let sum = serde_json::from_str::<Vec<Money>>("[0.1, 0.2]")
+        .unwrap()
+        .into_iter()
+        .try_fold(Money::default(), Money::checked_add)
+        .unwrap();
+
+    assert_eq!(sum, Money::rub(dec!(0.3)));
+    assert_eq!(sum.to_string(), "0.30");
+    assert_eq!(Money::rub(dec!(0.125)).to_string(), "0.13");
+    assert_eq!(Money::rub(dec!(-0.125)).to_string(), "-0.13");
+    assert_eq!(Money::rub(dec!(33.33)) * dec!(3), Money::rub(dec!(99.99)));
+    assert_eq!(
+        Money::from_kopecks(150_000, CurrencyCode::RUB).kopecks(),
+        150_000
+    );
+}
+
+#[test]
+fn different_currencies_do_not_mix() {
+    let rub = Money::rub(dec!(10));
+    let usd = Money::new(dec!(10), CurrencyCode::USD);
+
+    assert_ne!(rub, usd);
+    assert_eq!(rub.checked_add(usd), None);
+    assert_eq!(rub.partial_cmp(&usd), None);
+    assert!(rub < Money::rub(dec!(10.01)));
+}
+
+#[test]
+fn amount_carries_currency_into_money() {
+    let json = r#"{"amount": 12.5, "amountNat": 1250, "currency": "USD"}"#;
+
+    let amount: Amount = serde_json::from_str(json).unwrap();
+
+    assert_eq!(amount.amount, Money::new(dec!(12.5), CurrencyCode::USD));
+    assert_eq!(amount.currency(), CurrencyCode::USD);
+    assert_eq!(
+        serde_json::to_value(&amount).unwrap(),
+        serde_json::json!({"amount": 12.5, "amountNat": 1250, "currency": "USD"})
+    );
+}
+
+#[test]
+fn payload_amount_must_be_positive() {
+    let zero = RefundPayload {
+        amount: Money::default(),
+    };
+    let positive = RefundPayload {
+        amount: Money::rub(dec!(0.01)),
+    };
+
+    assert!(zero.validate().is_err());
+    assert!(positive.validate().is_ok());
+}
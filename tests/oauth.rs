@@ -0,0 +1,185 @@
+mod common;
+
+use axum::{
+    Form, Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+};
+use chrono::{Duration, Utc};
+use serde_json::{Value, json};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+use tochka_sdk::{
+    Client, Environment, Error, OAuthConfig, OAuthTokenProvider, TokenPair, testing::mock_jwk,
+};
+
+async fn token(
+    State(hits): State<Arc<AtomicUsize>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Json<Value> {
+    hits.fetch_add(1, Ordering::SeqCst);
+    assert_eq!(form["grant_type"], "refresh_token");
+    assert_eq!(form["refresh_token"], "refresh-1");
+    Json(json!({
+        "access_token": "fresh",
+        "refresh_token": "refresh-2",
+        "token_type": "bearer",
+        "expires_in": 86400
+    }))
+}
+
+async fn customers(headers: HeaderMap) -> Result<Json<Value>, StatusCode> {
+    if headers["authorization"] != "Bearer fresh" {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(Json(json!({
+        "Data": { "Customer": [] },
+        "Links": { "self": "http://localhost" },
+        "Meta": { "totalPages": 1 }
+    })))
+}
+
+async fn start() -> String {
+    start_counting().await.0
+}
+
+/// Сервер и счётчик обращений к token endpoint.
+async fn start_counting() -> (String, Arc<AtomicUsize>) {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let app = Router::new()
+        .route("/connect/token", post(token))
+        .route("/open-banking/v1.0/customers", get(customers))
+        .with_state(hits.clone());
+    let addr = common::listen(app).await;
+    (format!("http://{addr}/"), hits)
+}
+
+async fn client_with(base: &str, tokens: TokenPair) -> (Client, Arc<Mutex<Vec<TokenPair>>>) {
+    let persisted = Arc::new(Mutex::new(Vec::new()));
+    let sink = persisted.clone();
+    let config = OAuthConfig::new("client", "secret", "https://example.com/callback")
+        .token_url(format!("{base}connect/token"));
+    let provider = OAuthTokenProvider::new(config, tokens)
+        .on_refresh(move |pair| sink.lock().unwrap().push(pair.clone()));
+    let client = Client::builder()
+        .environment(Environment::Production)
+        .base_url(base)
+        .token_provider(provider)
+        .jwk(mock_jwk())
+        .build()
+        .await
+        .unwrap();
+    (client, persisted)
+}
+
+#[tokio::test]
+async fn refreshes_expiring_token_before_request() {
+    let base = start().await;
+    let (client, persisted) = client_with(
+        &base,
+        TokenPair {
+            access_token: "stale".into(),
+            refresh_token: Some("refresh-1".into()),
+            expires_at: Utc::now() - Duration::minutes(1),
+        },
+    )
+    .await;
+
+    client.get_customers_list().await.unwrap();
+
+    let persisted = persisted.lock().unwrap();
+    assert_eq!(persisted.len(), 1);
+    assert_eq!(persisted[0].access_token, "fresh");
+    assert_eq!(persisted[0].refresh_token.as_deref(), Some("refresh-2"));
+}
+
+#[tokio::test]
+async fn retries_once_after_unauthorized() {
+    let base = start().await;
+    let (client, persisted) = client_with(
+        &base,
+        TokenPair {
+            access_token: "revoked".into(),
+            refresh_token: Some("refresh-1".into()),
+            expires_at: Utc::now() + Duration::hours(1),
+        },
+    )
+    .await;
+
+    client.get_customers_list().await.unwrap();
+
+    assert_eq!(persisted.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn concurrent_unauthorized_requests_refresh_once() {
+    let (base, hits) = start_counting().await;
+    let (client, persisted) = client_with(
+        &base,
+        TokenPair {
+            access_token: "revoked".into(),
+            refresh_token: Some("refresh-1".into()),
+            expires_at: Utc::now() + Duration::hours(1),
+        },
+    )
+    .await;
+
+    let results = futures_util::future::join_all((0..8).map(|_| client.get_customers_list())).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(persisted.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn builds_authorization_url() {
+    let config = OAuthConfig::new("client", "secret", "https://example.com/callback");
+    let url = config
+        .authorization_url("consent-1", "state-1", "accounts")
+        .unwrap()
+        .to_string();
+
+    assert!(url.starts_with("https://enter.tochka.com/connect/authorize?"));
+    assert!(url.contains("client_id=client"));
+    assert!(url.contains("consent_id=consent-1"));
+    assert!(url.contains("redirect_uri=https%3A%2F%2Fexample.com%2Fcallback"));
+}
+
+#[tokio::test]
+async fn slow_token_endpoint_is_a_timeout() {
+    let app = Router::new().route(
+        "/connect/token",
+        post(|| async {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            StatusCode::OK
+        }),
+    );
+    let addr = common::listen(app).await;
+    let config = OAuthConfig::new("client", "secret", "https://example.com/callback")
+        .token_url(format!("http://{addr}/connect/token"));
+    let http = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let err = config.refresh_token(&http, "refresh-1").await.unwrap_err();
+
+    assert!(matches!(err, Error::Timeout));
+}
+
+#[test]
+fn invalid_authorize_url_is_an_error() {
+    let mut config = OAuthConfig::new("client", "secret", "https://example.com/callback");
+    config.authorize_url = "not a url".into();
+
+    assert!(matches!(
+        config.authorization_url("consent-1", "state-1", "accounts"),
+        Err(Error::Config(_))
+    ));
+}
@@ -1,6 +1,7 @@
 use chrono::DateTime;
+use rust_decimal_macros::dec;
 use tochka_sdk::{
-    Data, PaginatedResponse, PaymentMode, PaymentOperation, PaymentPageData, PaymentStatus,
+    Data, Money, PaginatedResponse, PaymentMode, PaymentOperation, PaymentPageData, PaymentStatus,
 };
 use uuid::uuid;
 
@@ -40,7 +41,7 @@ fn deserialize_payment_operation_creation_example() {
         Some("Футболка женская молочная")
     );
     assert!(matches!(parsed.data.status, PaymentStatus::Created));
-    assert_eq!(parsed.data.amount, 1234.0);
+    assert_eq!(parsed.data.amount, Money::rub(dec!(1234)));
     assert_eq!(
         parsed.data.operation_id,
         uuid!("48232c9a-ce82-1593-3cb6-5c85a1ffef8f")
@@ -122,7 +123,7 @@ fn deserialize_payment_operation_list_example() {
             PaymentMode::Dolyame
         ]
     );
-    assert_eq!(operation.amount, 1234.0);
+    assert_eq!(operation.amount, Money::rub(dec!(1234)));
     assert_eq!(operation.order.as_ref().unwrap().len(), 1);
     let created_at = DateTime::parse_from_rfc3339("2022-10-18T08:28:59+00:00")
         .unwrap()
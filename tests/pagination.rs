@@ -0,0 +1,124 @@
+mod common;
+
+use axum::{Json, Router, extract::Query, routing::get};
+use futures_util::TryStreamExt;
+use serde_json::{Value, json};
+use std::{collections::HashMap, time::Duration};
+use tochka_sdk::{Client, Error, PageOptions, PaymentListQuery};
+
+fn customer(code: &str) -> Value {
+    json!({
+        "customerCode": code,
+        "customerType": "Business",
+        "isResident": true,
+        "taxCode": "6600000000",
+        "fullName": "ООО Тест",
+        "shortName": "Тест"
+    })
+}
+
+async fn customers(Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    let (items, next) = match query.get("page").map(String::as_str) {
+        None => (
+            vec![customer("300000001"), customer("300000002")],
+            Some("/open-banking/v1.0/customers?page=2"),
+        ),
+        _ => (vec![customer("300000003")], None),
+    };
+
+    Json(json!({
+        "Data": { "Customer": items },
+        "Links": { "self": "http://localhost/open-banking/v1.0/customers", "next": next },
+        "Meta": { "totalPages": 2 }
+    }))
+}
+
+// Первая страница ведёт на чужой хост: туда нельзя отправлять токен.
+async fn balances(Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    assert_eq!(query["perPage"], "5");
+    Json(json!({
+        "Data": { "Balance": [] },
+        "Links": {
+            "self": "http://localhost/open-banking/v1.0/accounts/balances",
+            "next": "https://attacker.example/open-banking/v1.0/accounts/balances?page=2"
+        },
+        "Meta": { "totalPages": 2 }
+    }))
+}
+
+async fn payments(Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    let page: u32 = query["page"].parse().unwrap();
+    assert_eq!(query["perPage"], "2");
+    // Вторая страница отвечает медленнее третьей, порядок всё равно должен сохраниться.
+    if page == 2 {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let operations: Vec<Value> = (0..2)
+        .map(|i| {
+            json!({
+                "amount": page * 10 + i,
+                "status": "APPROVED",
+                "operationId": format!("beeac8a4-6047-3f38-8922-a664e6b5c4{page}{i}"),
+                "paymentLink": "https://merch.example.com/order/?uuid=beeac8a4"
+            })
+        })
+        .collect();
+
+    Json(json!({
+        "Data": { "Operation": operations },
+        "Links": { "self": "http://localhost/acquiring/v1.0/payments" },
+        "Meta": { "totalPages": 3 }
+    }))
+}
+
+async fn client() -> Client {
+    let app = Router::new()
+        .route("/open-banking/v1.0/customers", get(customers))
+        .route("/open-banking/v1.0/accounts/balances", get(balances))
+        .route("/acquiring/v1.0/payments", get(payments));
+    common::serve(app).await.0
+}
+
+#[tokio::test]
+async fn customers_stream_follows_next_links() {
+    let client = client().await;
+
+    let customers: Vec<_> = client
+        .customers_stream(PageOptions::default())
+        .try_collect()
+        .await
+        .unwrap();
+
+    let codes: Vec<_> = customers.iter().map(|c| c.customer_code.as_str()).collect();
+    assert_eq!(codes, ["300000001", "300000002", "300000003"]);
+}
+
+#[tokio::test]
+async fn next_link_to_another_host_is_rejected() {
+    let client = client().await;
+
+    let result: Result<Vec<_>, _> = client
+        .balances_stream(PageOptions::default().per_page(5))
+        .try_collect()
+        .await;
+
+    assert!(matches!(result, Err(Error::ForeignNextLink(url)) if url.contains("attacker.example")));
+}
+
+#[tokio::test]
+async fn payment_operations_stream_walks_pages_in_order() {
+    let client = client().await;
+    let options = PageOptions::default().per_page(2).concurrency(2);
+
+    let operations: Vec<_> = client
+        .payment_operations_stream(PaymentListQuery::new(Some("300000092".into())), options)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let amounts: Vec<_> = operations.iter().map(|op| op.amount.to_string()).collect();
+    assert_eq!(
+        amounts,
+        ["10.00", "11.00", "20.00", "21.00", "30.00", "31.00"]
+    );
+}
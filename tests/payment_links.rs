@@ -1,7 +1,10 @@
 use chrono::DateTime;
+use rust_decimal_macros::dec;
 use uuid::Uuid;
 
-use tochka_sdk::{Data, PaymentMode, PaymentStatus, Refund, RegistryPageData, RetailerPageData};
+use tochka_sdk::{
+    Data, Money, PaymentMode, PaymentStatus, Refund, RegistryPageData, RetailerPageData,
+};
 
 #[test]
 fn deserialize_refund_payment_example() {
@@ -30,7 +33,7 @@ fn deserialize_refund_payment_example() {
         parsed.data.operation_id,
         Uuid::parse_str("48232c9a-ce82-1593-3cb6-5c85a1ffef8f").unwrap()
     );
-    assert_eq!(parsed.data.amount, 500.0);
+    assert_eq!(parsed.data.amount, Money::rub(dec!(500)));
     assert_eq!(parsed.data.order_id, "1");
 }
 
@@ -66,13 +69,13 @@ fn deserialize_payment_registry_example() {
 
     assert_eq!(item.purpose, "Футболка женская молочная");
     assert!(matches!(item.status, PaymentStatus::Created));
-    assert_eq!(item.amount, 18548.39);
+    assert_eq!(item.amount, Money::rub(dec!(18548.39)));
     let parsed_time = DateTime::parse_from_rfc3339("2022-10-18T08:28:59+00:00")
         .unwrap()
         .with_timezone(&chrono::Utc);
     assert_eq!(item.time, parsed_time);
     assert_eq!(item.number, 123456);
-    assert_eq!(item.enrollment_amount, 18448.39);
+    assert_eq!(item.enrollment_amount, Money::rub(dec!(18448.39)));
 }
 
 #[test]
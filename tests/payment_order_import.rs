@@ -0,0 +1,165 @@
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+use tochka_sdk::{Error, ExchangeError, Money, PaymentOrderPayload};
+
+fn parse(text: &str) -> Result<Vec<PaymentOrderPayload>, Error> {
+    PaymentOrderPayload::from_1c_exchange(text)
+}
+
+fn errors(text: &str) -> Vec<ExchangeError> {
+    match parse(text) {
+        Err(Error::Exchange(errors)) => errors,
+        other => panic!("expected exchange error, got {other:?}"),
+    }
+}
+
+const DOCUMENT: &str = "1CClientBankExchange
+ВерсияФормата=1.03
+СекцияДокумент=Платежное поручение
+Номер=1
+Дата=01.02.2019
+Сумма=10.00
+ПлательщикСчет=40817810802000000008
+ПлательщикБИК=044525104
+ПолучательСчет=40702810900000000001
+ПолучательБИК=044525593
+ПолучательИНН=1234567890
+Получатель1=ООО Ромашка
+НазначениеПлатежа=Оплата
+КонецДокумента
+КонецФайла
+";
+
+#[test]
+fn parse_windows_1251_file_into_payment_orders() {
+    let orders =
+        PaymentOrderPayload::from_1c_bytes(include_bytes!("fixtures/payment_orders.1c.txt"))
+            .unwrap();
+
+    assert_eq!(orders.len(), 2);
+
+    let order = &orders[0];
+    assert_eq!(order.account_code, "40817810802000000008");
+    assert_eq!(order.bank_code, "044525104");
+    assert_eq!(order.counterparty_account_number, "40702810900000000001");
+    assert_eq!(order.counterparty_bank_bic, "044525593");
+    assert_eq!(order.counterparty_name, "ООО Ромашка");
+    assert_eq!(order.counterparty_inn.as_deref(), Some("1234567890"));
+    assert_eq!(order.counterparty_kpp.as_deref(), Some("123456789"));
+    assert_eq!(
+        order.counterparty_bank_corr_account.as_deref(),
+        Some("30101810200000000593")
+    );
+    assert_eq!(order.payment_amount, Money::rub(dec!(1500.75)));
+    assert_eq!(
+        order.payment_date,
+        NaiveDate::from_ymd_opt(2019, 2, 1).unwrap()
+    );
+    assert_eq!(order.payment_number.as_deref(), Some("18"));
+    assert_eq!(order.payment_priority.as_deref(), Some("5"));
+    assert_eq!(
+        order.payment_purpose,
+        "Оплата по счёту №15 от 20.01.2019, НДС не облагается"
+    );
+    assert_eq!(order.tax_info_status, None);
+}
+
+#[test]
+fn parse_budget_payment_tax_fields() {
+    let orders =
+        PaymentOrderPayload::from_1c_bytes(include_bytes!("fixtures/payment_orders.1c.txt"))
+            .unwrap();
+    let order = &orders[1];
+
+    assert_eq!(order.payment_purpose, "Единый налоговый платёж");
+    assert_eq!(order.supplier_bill_id, None);
+    assert_eq!(order.tax_info_status.as_deref(), Some("01"));
+    assert_eq!(order.tax_info_kbk.as_deref(), Some("18201061201010000510"));
+    assert_eq!(order.tax_info_okato.as_deref(), Some("45388000"));
+    assert_eq!(order.tax_info_reason_code.as_deref(), Some("0"));
+    assert_eq!(order.tax_info_period.as_deref(), Some("0"));
+    assert_eq!(order.tax_info_document_number.as_deref(), Some("0"));
+    assert_eq!(order.tax_info_document_date.as_deref(), Some("0"));
+    assert_eq!(order.budget_payment_code, None);
+}
+
+#[test]
+fn parse_dos_encoded_file() {
+    let text = DOCUMENT.replace("ВерсияФормата=1.03", "ВерсияФормата=1.03\nКодировка=DOS");
+    let (bytes, _, _) = encoding_rs::IBM866.encode(&text);
+
+    let orders = PaymentOrderPayload::from_1c_bytes(&bytes).unwrap();
+    assert_eq!(orders[0].counterparty_name, "ООО Ромашка");
+}
+
+#[test]
+fn reports_invalid_values_with_line_numbers() {
+    let text = DOCUMENT
+        .replace("Сумма=10.00", "Сумма=десять")
+        .replace("ПолучательИНН=1234567890", "ПолучательИНН=12345");
+
+    let errors = errors(&text);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].line, 6);
+    assert_eq!(errors[0].field.as_deref(), Some("Сумма"));
+    assert_eq!(errors[1].line, 11);
+    assert_eq!(errors[1].field.as_deref(), Some("ПолучательИНН"));
+}
+
+#[test]
+fn reports_missing_fields_at_document_end() {
+    let text = DOCUMENT.replace("ПолучательБИК=044525593\n", "");
+
+    let errors = errors(&text);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, 13);
+    assert_eq!(errors[0].field.as_deref(), Some("ПолучательБИК"));
+    assert_eq!(
+        errors[0].to_string(),
+        "line 13: ПолучательБИК: required field is missing in the document at lines 3-13"
+    );
+}
+
+#[test]
+fn reports_payload_validation_errors_at_source_line() {
+    let text = DOCUMENT.replace(
+        "ПолучательСчет=40702810900000000001",
+        "ПолучательСчет=4070281090",
+    );
+
+    let errors = errors(&text);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, 9);
+    assert_eq!(errors[0].field.as_deref(), Some("ПолучательСчет"));
+}
+
+#[test]
+fn reports_structural_errors() {
+    let not_exchange = errors("Платежное поручение\n");
+    assert_eq!(not_exchange[0].line, 1);
+
+    let unclosed = errors(&DOCUMENT.replace("КонецДокумента\n", ""));
+    assert_eq!(unclosed.len(), 1);
+    assert_eq!(unclosed[0].line, 3);
+    assert_eq!(unclosed[0].message, "КонецДокумента is missing");
+
+    let truncated = errors(&DOCUMENT.replace("КонецФайла\n", ""));
+    assert_eq!(truncated[0].message, "КонецФайла is missing");
+
+    let malformed = errors(&DOCUMENT.replace("Номер=1", "Номер 1"));
+    assert_eq!(malformed.len(), 1);
+    assert_eq!(malformed[0].line, 4);
+    assert_eq!(malformed[0].message, "expected key=value");
+}
+
+#[test]
+fn rejects_unsupported_document_kinds() {
+    let text = DOCUMENT.replace(
+        "СекцияДокумент=Платежное поручение",
+        "СекцияДокумент=Аккредитив",
+    );
+
+    let errors = errors(&text);
+    assert_eq!(errors[0].line, 3);
+    assert_eq!(errors[0].field.as_deref(), Some("СекцияДокумент"));
+}
@@ -0,0 +1,133 @@
+use rust_decimal_macros::dec;
+use serde_json::json;
+use tochka_sdk::{
+    CreatePaymentWithReceiptPayload, Measure, Money, PaymentMethod, PaymentObject, ReceiptClient,
+    ReceiptItem, Supplier, TaxSystemCode, VatType,
+};
+use validator::Validate;
+
+fn payload(amount: Money) -> CreatePaymentWithReceiptPayload {
+    CreatePaymentWithReceiptPayload::new(
+        amount,
+        Some("300000092".into()),
+        "Заказ №1",
+        ReceiptClient::new("buyer@example.com").phone("+79001234567"),
+    )
+    .tax_system_code(TaxSystemCode::UsnIncome)
+    .item(
+        ReceiptItem::new("Кофе", Money::rub(dec!(349.90)), dec!(2))
+            .vat_type(VatType::None)
+            .payment_method(PaymentMethod::FullPayment)
+            .payment_object(PaymentObject::Goods)
+            .measure(Measure::Piece),
+    )
+    .item(ReceiptItem::new("Сахар", Money::rub(dec!(120.50)), dec!(0.5)).measure(Measure::Kilogram))
+}
+
+#[test]
+fn serializes_receipt_payload() {
+    let payload = payload(Money::rub(dec!(760.05)));
+    assert!(payload.validate().is_ok());
+
+    let json = serde_json::to_value(&payload).unwrap();
+    assert_eq!(json["taxSystemCode"], json!("usn_income"));
+    assert_eq!(json["Client"]["email"], json!("buyer@example.com"));
+    assert_eq!(json["Items"][0]["amount"], json!(349.9));
+    assert_eq!(json["Items"][0]["vatType"], json!("none"));
+    assert_eq!(json["Items"][1]["measure"], json!("кг."));
+    assert!(json.get("Supplier").is_none());
+    assert_eq!(json["amount"], json!(760.05));
+    assert_eq!(json["purpose"], json!("Заказ №1"));
+}
+
+#[test]
+fn unset_tax_system_code_is_omitted() {
+    let payload = CreatePaymentWithReceiptPayload::new(
+        Money::rub(dec!(100)),
+        Some("300000092".into()),
+        "Заказ",
+        ReceiptClient::new("buyer@example.com"),
+    )
+    .ttl(600);
+
+    let json = serde_json::to_value(&payload).unwrap();
+    assert!(json.get("taxSystemCode").is_none());
+    assert!(json.get("base").is_none());
+    assert_eq!(json["ttl"], json!(600));
+}
+
+#[test]
+fn items_must_add_up_to_amount() {
+    let errors = payload(Money::rub(dec!(760))).validate().unwrap_err();
+
+    let schema = &errors.field_errors()["__all__"];
+    assert_eq!(schema[0].code, "items_total");
+    assert_eq!(schema[0].params["items_total"], json!("760.05"));
+}
+
+#[test]
+fn nested_receipt_fields_are_validated() {
+    let payload = CreatePaymentWithReceiptPayload::new(
+        Money::rub(dec!(100)),
+        None,
+        "Заказ",
+        ReceiptClient::new("not-an-email"),
+    )
+    .item(ReceiptItem::new("", Money::rub(dec!(100)), dec!(1)))
+    .supplier(Supplier {
+        phone: "123".into(),
+        name: "ООО Ромашка".into(),
+        tax_code: "7700000000".into(),
+    });
+
+    let errors = payload.validate().unwrap_err();
+    let fields = errors.errors();
+    assert!(fields.contains_key("client"));
+    assert!(fields.contains_key("items"));
+    assert!(fields.contains_key("supplier"));
+    assert!(!fields.contains_key("__all__"));
+}
+
+#[test]
+fn empty_receipt_is_rejected() {
+    let payload = CreatePaymentWithReceiptPayload::new(
+        Money::rub(dec!(100)),
+        None,
+        "Заказ",
+        ReceiptClient::new("buyer@example.com"),
+    );
+
+    let errors = payload.validate().unwrap_err();
+    assert!(errors.errors().contains_key("items"));
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn creates_payment_with_receipt_on_mock() {
+    let server = tochka_sdk::MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let operation = client
+        .create_payment_with_receipt(payload(Money::rub(dec!(760.05))))
+        .await
+        .unwrap()
+        .data;
+
+    let stored = server.payment(operation.operation_id).unwrap();
+    assert_eq!(stored.items.unwrap().len(), 2);
+    assert_eq!(stored.client.unwrap().email, "buyer@example.com");
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn invalid_receipt_is_not_sent() {
+    let server = tochka_sdk::MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let result = client
+        .create_payment_with_receipt(payload(Money::rub(dec!(760))))
+        .await;
+
+    assert!(matches!(result, Err(tochka_sdk::Error::Validation(_))));
+    assert!(server.payments().is_empty());
+}
@@ -0,0 +1,101 @@
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+use tochka_sdk::{
+    Data, DateValue, Money, PaymentOrderPayload, PaymentOrderRequest, PaymentOrderStatus,
+    PaymentOrderStatusInfo, TaxFields,
+};
+use validator::Validate;
+
+fn order() -> PaymentOrderPayload {
+    PaymentOrderPayload::new(
+        "40702810840020002503",
+        "044525104",
+        "044525593",
+        "40702810100000000001",
+        "ООО Ромашка",
+        Money::rub(dec!(1500.5)),
+        NaiveDate::from_ymd_opt(2025, 4, 11).unwrap(),
+        "Оплата по счёту № 1. Без НДС",
+    )
+    .counterparty_inn("7701234567")
+    .counterparty_kpp("770101001")
+}
+
+#[test]
+fn serialize_payment_order_payload() {
+    let json = serde_json::to_value(order()).unwrap();
+
+    assert_eq!(json["accountCode"], "40702810840020002503");
+    assert_eq!(json["counterpartyINN"], "7701234567");
+    assert_eq!(json["counterpartyKPP"], "770101001");
+    assert_eq!(json["paymentAmount"], 1500.5);
+    assert_eq!(json["paymentDate"], "2025-04-11");
+    assert!(order().validate().is_ok());
+}
+
+#[test]
+fn budget_payment_maps_tax_fields() {
+    let payload = order().tax_fields(TaxFields {
+        base: Some("ТП".into()),
+        document_date: Some(DateValue::Number(0)),
+        document_number: Some("0".into()),
+        field107: Some("МС.03.2025".into()),
+        kbk: Some("18210102010011000110".into()),
+        oktmo: Some("45000000".into()),
+        originator_status: Some("01".into()),
+        type_: None,
+    });
+    let json = serde_json::to_value(payload).unwrap();
+
+    assert_eq!(json["taxInfoKBK"], "18210102010011000110");
+    assert_eq!(json["taxInfoOKATO"], "45000000");
+    assert_eq!(json["taxInfoStatus"], "01");
+    assert_eq!(json["taxInfoPeriod"], "МС.03.2025");
+    assert_eq!(json["taxInfoReasonCode"], "ТП");
+    assert_eq!(json["taxInfoDocumentDate"], "0");
+}
+
+#[test]
+fn deserialize_payment_order_response() {
+    let json = r#"
+{
+  "Data": {
+    "requestId": "req-123",
+    "redirectURL": "https://enter.tochka.com/payment/sign/req-123"
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/payment/v1.0/order"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<PaymentOrderRequest> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(parsed.data.request_id, "req-123");
+    assert!(parsed.data.redirect_url.is_some());
+}
+
+#[test]
+fn deserialize_payment_order_status() {
+    let json = r#"
+{
+  "Data": {
+    "status": "Wait For Sign"
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/payment/v1.0/status/req-123"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<PaymentOrderStatusInfo> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(parsed.data.status, PaymentOrderStatus::WaitForSign);
+    assert!(parsed.data.error_code.is_none());
+}
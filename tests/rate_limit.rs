@@ -0,0 +1,80 @@
+mod common;
+
+use axum::{Router, routing::get};
+use std::{sync::Arc, time::Duration};
+use tochka_sdk::{RateLimit, RateLimiter, Service};
+use tokio::time::Instant;
+
+#[test]
+fn service_is_resolved_from_path() {
+    assert_eq!(
+        Service::from_path("/acquiring/v1.0/payments"),
+        Some(Service::Acquiring)
+    );
+    assert_eq!(
+        Service::from_path("open-banking/v1.0/customers"),
+        Some(Service::OpenBanking)
+    );
+    assert_eq!(Service::from_path("unknown/v1.0"), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn limiter_waits_for_tokens_per_service() {
+    let limiter = RateLimiter::new()
+        .default_limit(RateLimit::per_second(100))
+        .limit(Service::Acquiring, RateLimit::per_second(2));
+    let start = Instant::now();
+
+    limiter.acquire(Service::Acquiring).await;
+    limiter.acquire(Service::Acquiring).await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    limiter.acquire(Service::OpenBanking).await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    limiter.acquire(Service::Acquiring).await;
+    let waited = start.elapsed();
+    assert!(waited >= Duration::from_millis(499) && waited <= Duration::from_millis(501));
+}
+
+#[tokio::test]
+async fn client_clones_share_the_limiter() {
+    let app = Router::new().route(
+        "/open-banking/v1.0/customers/{code}",
+        get(|| async {
+            r#"{
+                "Data": {
+                    "customerCode": "300000092",
+                    "customerType": "Business",
+                    "isResident": true,
+                    "taxCode": "6600000000",
+                    "fullName": "ООО Тест",
+                    "shortName": "Тест"
+                },
+                "Links": { "self": "http://localhost" },
+                "Meta": { "totalPages": 1 }
+            }"#
+        }),
+    );
+    let addr = common::listen(app).await;
+
+    let limiter = Arc::new(
+        RateLimiter::new().limit(Service::OpenBanking, RateLimit::per_second(10).burst(1)),
+    );
+    let client = common::client_builder(addr)
+        .rate_limiter(limiter)
+        .build()
+        .await
+        .unwrap();
+    let (second, third) = (client.clone(), client.clone());
+    let start = Instant::now();
+
+    let (a, b, c) = tokio::join!(
+        client.get_customer_info("300000092"),
+        second.get_customer_info("300000092"),
+        third.get_customer_info("300000092"),
+    );
+
+    assert!(a.is_ok() && b.is_ok() && c.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(190));
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use rust_decimal_macros::dec;
+use serde_json::json;
+use tochka_sdk::{Error, Money, PaymentOperation, PaymentStatus, RefundStatus, RefundSummary};
+
+fn operation(status: &str, orders: serde_json::Value) -> PaymentOperation {
+    serde_json::from_value(json!({
+        "customerCode": "300000092",
+        "amount": 1000.0,
+        "status": status,
+        "operationId": "beeac93c-ad4b-4ab1-9d3c-4fd5ec3a2e2a",
+        "paymentLink": "https://merch.tochka.com/order/?uuid=beeac93c-ad4b-4ab1-9d3c-4fd5ec3a2e2a",
+        "Order": orders
+    }))
+    .unwrap()
+}
+
+#[test]
+fn summarizes_partial_refunds() {
+    let operation = operation(
+        "REFUNDED_PARTIALLY",
+        json!([
+            { "orderId": "1", "type": "approval", "amount": 1000.0, "time": "2025-04-10T10:00:00+03:00" },
+            { "orderId": "2", "type": "refund", "amount": 250.5, "time": "2025-04-11T10:00:00+03:00" },
+            { "orderId": "3", "type": "refund", "amount": "100.00", "time": "2025-04-12T10:00:00+03:00" }
+        ]),
+    );
+
+    let summary = operation.refund_summary();
+    assert_eq!(summary.status, PaymentStatus::RefundedPartially);
+    assert_eq!(summary.captured, Money::rub(dec!(1000)));
+    assert_eq!(summary.refunded, Money::rub(dec!(350.50)));
+    assert_eq!(summary.remaining, Money::rub(dec!(649.50)));
+    assert_eq!(summary.refunds.len(), 2);
+    assert_eq!(summary.refund("3").unwrap().status, RefundStatus::Completed);
+    assert!(summary.refund("1").is_none());
+}
+
+#[test]
+fn latest_refund_is_in_progress_while_operation_is_on_refund() {
+    let summary = operation(
+        "ON-REFUND",
+        json!([
+            { "orderId": "2", "type": "refund", "amount": 100.0, "time": "2025-04-11T10:00:00+03:00" },
+            { "orderId": "3", "type": "refund", "amount": 50.0, "time": "2025-04-12T10:00:00+03:00" }
+        ]),
+    )
+    .refund_summary();
+
+    assert_eq!(summary.refund("2").unwrap().status, RefundStatus::Completed);
+    assert_eq!(
+        summary.refund("3").unwrap().status,
+        RefundStatus::InProgress
+    );
+}
+
+#[test]
+fn paid_operation_without_orders_is_fully_captured() {
+    let summary = RefundSummary::from(&operation("APPROVED", json!(null)));
+    assert_eq!(summary.captured, Money::rub(dec!(1000)));
+    assert_eq!(summary.remaining, Money::rub(dec!(1000)));
+    assert!(summary.refunds.is_empty());
+
+    let unpaid = RefundSummary::from(&operation("AUTHORIZED", json!(null)));
+    assert!(unpaid.captured.is_zero());
+    assert!(unpaid.remaining.is_zero());
+}
+
+#[test]
+fn over_refund_is_rejected() {
+    let summary = operation(
+        "REFUNDED_PARTIALLY",
+        json!([{ "orderId": "2", "type": "refund", "amount": 900.0, "time": "2025-04-11T10:00:00+03:00" }]),
+    )
+    .refund_summary();
+
+    assert!(summary.check_refund(Money::rub(dec!(100))).is_ok());
+    let err = summary.check_refund(Money::rub(dec!(100.01))).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::RefundExceedsRemaining { requested, remaining, .. }
+            if requested == Money::rub(dec!(100.01)) && remaining == Money::rub(dec!(100))
+    ));
+}
+
+#[tokio::test]
+async fn summary_of_missing_operation_keeps_raw_body() {
+    use axum::{Json, Router, routing::get};
+
+    let app = Router::new().route(
+        "/acquiring/v1.0/payments/{id}",
+        get(|| async {
+            Json(json!({
+                "Data": { "Operation": [] },
+                "Links": { "self": "http://localhost" },
+                "Meta": { "totalPages": 1 }
+            }))
+        }),
+    );
+    let (client, _) = common::serve(app).await;
+
+    let err = client
+        .refund_summary("beeac93c-ad4b-4ab1-9d3c-4fd5ec3a2e2a")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, Error::MissingData { path, raw } if path == "Data.Operation" && raw.contains("\"Operation\":[]"))
+    );
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn checked_refund_against_mock() {
+    use tochka_sdk::{
+        CreatePaymentPayload, MOCK_CUSTOMER_CODE, MockServer, PaymentPath, RefundPayload,
+    };
+
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+    let operation = client
+        .create_payment_operation(
+            CreatePaymentPayload::new(
+                Money::rub(dec!(500)),
+                Some(MOCK_CUSTOMER_CODE.into()),
+                "Заказ",
+            ),
+            PaymentPath::Standard,
+        )
+        .await
+        .unwrap()
+        .data;
+    let id = operation.operation_id.to_string();
+    server
+        .complete_payment(operation.operation_id, PaymentStatus::Approved)
+        .await
+        .unwrap();
+
+    let refund = client
+        .refund_payment_operation_checked(
+            id.as_str(),
+            RefundPayload {
+                amount: Money::rub(dec!(200)),
+            },
+        )
+        .await
+        .unwrap()
+        .data;
+
+    let err = client
+        .refund_payment_operation_checked(
+            id.as_str(),
+            RefundPayload {
+                amount: Money::rub(dec!(300.01)),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::RefundExceedsRemaining { .. }));
+
+    let summary = client.refund_summary(id.as_str()).await.unwrap();
+    assert_eq!(summary.refunded, Money::rub(dec!(200)));
+    assert_eq!(summary.remaining, Money::rub(dec!(300)));
+    assert_eq!(summary.refunds.len(), 1);
+
+    let stored = client
+        .payment_refund(id.as_str(), &refund.order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.amount, Money::rub(dec!(200)));
+    assert_eq!(stored.status, RefundStatus::Completed);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use axum::{
+    Router,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+    routing::{get, post},
+};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tochka_sdk::{Client, Error, IDEMPOTENCY_KEY_HEADER, RetryPolicy};
+
+#[derive(Clone, Default)]
+struct Counters {
+    customer: Arc<AtomicUsize>,
+    order: Arc<AtomicUsize>,
+    limited: Arc<AtomicUsize>,
+}
+
+async fn customer(State(counters): State<Counters>) -> impl IntoResponse {
+    if counters.customer.fetch_add(1, Ordering::SeqCst) < 2 {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, "0")],
+            "busy".to_string(),
+        );
+    }
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        r#"{
+            "Data": {
+                "customerCode": "300000092",
+                "customerType": "Business",
+                "isResident": true,
+                "taxCode": "6600000000",
+                "fullName": "ООО Тест",
+                "shortName": "Тест"
+            },
+            "Links": { "self": "http://localhost" },
+            "Meta": { "totalPages": 1 }
+        }"#
+        .to_string(),
+    )
+}
+
+async fn order(State(counters): State<Counters>, headers: HeaderMap) -> StatusCode {
+    counters.order.fetch_add(1, Ordering::SeqCst);
+    assert!(headers.get(header::AUTHORIZATION).is_some());
+    StatusCode::BAD_GATEWAY
+}
+
+async fn limited(State(counters): State<Counters>) -> impl IntoResponse {
+    counters.limited.fetch_add(1, Ordering::SeqCst);
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, "3600")],
+    )
+}
+
+async fn setup() -> (Client, Counters, String) {
+    let counters = Counters::default();
+    let app = Router::new()
+        .route("/open-banking/v1.0/customers/{code}", get(customer))
+        .route("/open-banking/v1.0/limited", get(limited))
+        .route("/payment/v1.0/order", post(order))
+        .with_state(counters.clone());
+    let addr = common::listen(app).await;
+
+    let client = common::client_builder(addr)
+        .retry_policy(RetryPolicy::default().base_delay(Duration::from_millis(1)))
+        .build()
+        .await
+        .unwrap();
+
+    (client, counters, format!("http://{addr}"))
+}
+
+#[test]
+fn backoff_grows_exponentially_and_is_capped() {
+    let policy = RetryPolicy::default()
+        .base_delay(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(300))
+        .jitter(false);
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(300));
+    assert_eq!(policy.delay(1, Some(Duration::from_secs(1))), None);
+
+    let jittered = policy.jitter(true).backoff(2);
+    assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn get_is_retried_until_success() {
+    let (client, counters, _) = setup().await;
+
+    let customer = client.get_customer_info("300000092").await.unwrap();
+
+    assert_eq!(customer.data.customer_code, "300000092");
+    assert_eq!(counters.customer.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn post_is_retried_only_with_idempotency_key() {
+    let (client, counters, base) = setup().await;
+    let http = reqwest::Client::new();
+    let url = format!("{base}/payment/v1.0/order");
+
+    let plain = client.send_raw(http.post(&url).body("{}")).await;
+    assert!(matches!(plain, Err(Error::Server(_))));
+    assert_eq!(counters.order.load(Ordering::SeqCst), 1);
+
+    let keyed = client
+        .send_raw(
+            http.post(&url)
+                .header(IDEMPOTENCY_KEY_HEADER, "3f1c2a1e-order-1")
+                .body("{}"),
+        )
+        .await;
+    assert!(matches!(keyed, Err(Error::Server(_))));
+    assert_eq!(counters.order.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn long_retry_after_is_not_waited_for() {
+    let (client, counters, base) = setup().await;
+
+    let result = client
+        .send_raw(reqwest::Client::new().get(format!("{base}/open-banking/v1.0/limited")))
+        .await;
+
+    assert!(matches!(result, Err(Error::TooManyRequests(_))));
+    assert_eq!(counters.limited.load(Ordering::SeqCst), 1);
+}
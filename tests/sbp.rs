@@ -0,0 +1,304 @@
+use rust_decimal_macros::dec;
+use tochka_sdk::{
+    CashboxQrActivationPayload, CashboxQrPayment, Data, Money, QrCode, QrCodePageData,
+    QrCodePayload, QrCodePaymentPageData, QrCodeStatus, QrCodeType, QrImageParams,
+    RegisteredQrCode, SbpAccountPageData, SbpContact, SbpEntityStatus, SbpLegalEntity,
+    SbpMerchantCapabilities, SbpMerchantPageData, SbpMerchantPayload, SbpPaymentStatus, SbpRefund,
+    SbpRefundPayload,
+};
+use validator::Validate;
+
+#[test]
+fn serialize_dynamic_qr_payload() {
+    let payload = QrCodePayload::dynamic_qr(Money::rub(dec!(1500)), "Оплата заказа № 7")
+        .ttl(30)
+        .image_params(QrImageParams::default());
+    let json = serde_json::to_value(&payload).unwrap();
+
+    assert!(payload.validate().is_ok());
+    assert_eq!(json["qrcType"], "02");
+    assert_eq!(json["amount"], 150_000);
+    assert_eq!(json["currency"], "RUB");
+    assert_eq!(json["imageParams"]["mediaType"], "image/png");
+}
+
+#[test]
+fn deserialize_registered_qr_code() {
+    let json = r#"
+{
+  "Data": {
+    "payload": "https://qr.nspk.ru/AS1000670LSS7DN18SJQDNP4B05KLJL2",
+    "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+    "image": {
+      "width": 300,
+      "height": 300,
+      "mediaType": "image/png",
+      "content": "iVBORw0KGgo="
+    }
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/qr-code/merchant/MA0000000552/40702810840020002503"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<RegisteredQrCode> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(parsed.data.qrc_id, "AS1000670LSS7DN18SJQDNP4B05KLJL2");
+    assert_eq!(parsed.data.image.unwrap().content, "iVBORw0KGgo=");
+}
+
+#[test]
+fn deserialize_qr_code_list() {
+    let json = r#"
+{
+  "Data": {
+    "qrCodeList": [
+      {
+        "accountId": "40702810840020002503",
+        "status": "Active",
+        "createdAt": "2024-01-01T10:00:00+00:00",
+        "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "legalId": "LA0000000123",
+        "merchantId": "MA0000000552",
+        "amount": 150000,
+        "commissionPercent": 0.7,
+        "currency": "RUB",
+        "paymentPurpose": "Оплата заказа № 7",
+        "qrcType": "02",
+        "templateVersion": "01",
+        "payload": "https://qr.nspk.ru/AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "sourceName": "shop",
+        "ttl": 30
+      }
+    ]
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/qr-code/legal-entity/LA0000000123"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<QrCodePageData> = serde_json::from_str(json).unwrap();
+    let qr: &QrCode = &parsed.data.qr_code_list[0];
+
+    assert_eq!(qr.status, QrCodeStatus::Active);
+    assert_eq!(qr.qrc_type, QrCodeType::Dynamic);
+    assert_eq!(qr.amount, Some(Money::rub(dec!(1500))));
+}
+
+#[test]
+fn deserialize_qr_payment_statuses() {
+    let json = r#"
+{
+  "Data": {
+    "paymentList": [
+      {
+        "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "code": "RQ00000",
+        "status": "Accepted",
+        "message": "Запрос обработан успешно",
+        "trxId": "A22031016256670100000533E625FCB3"
+      }
+    ]
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/qr-codes/AS1000670LSS7DN18SJQDNP4B05KLJL2/payment-status"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<QrCodePaymentPageData> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        parsed.data.payment_list[0].status,
+        SbpPaymentStatus::Accepted
+    );
+}
+
+#[test]
+fn serialize_merchant_payload() {
+    let payload = SbpMerchantPayload {
+        address: "ул. Ленина, 1".into(),
+        city: "Екатеринбург".into(),
+        country_code: "RU".into(),
+        country_sub_division_code: "65".into(),
+        zip_code: "620000".into(),
+        brand_name: "Кофейня".into(),
+        capabilities: SbpMerchantCapabilities::StaticAndDynamic,
+        contact_phone_number: Some("+79990000000".into()),
+        mcc: "5814".into(),
+        additional_contacts: Some(vec![SbpContact {
+            contact_type: "email".into(),
+            value: "shop@example.com".into(),
+        }]),
+    };
+    let json = serde_json::to_value(&payload).unwrap();
+
+    assert!(payload.validate().is_ok());
+    assert_eq!(json["capabilities"], "011");
+    assert_eq!(json["countrySubDivisionCode"], "65");
+    assert_eq!(json["additionalContacts"][0]["type"], "email");
+}
+
+#[test]
+fn deserialize_merchants_and_accounts() {
+    let merchants = r#"
+{
+  "Data": {
+    "merchantList": [
+      {
+        "merchantId": "MA0000000552",
+        "legalId": "LA0000000123",
+        "status": "Active",
+        "brandName": "Кофейня",
+        "capabilities": "011",
+        "mcc": "5814"
+      }
+    ]
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/merchant/legal-entity/LA0000000123"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+    let accounts = r#"
+{
+  "Data": {
+    "accountList": [
+      {
+        "accountId": "40702810840020002503",
+        "status": "Suspended",
+        "createdAt": "2024-01-01T10:00:00+00:00",
+        "legalId": "LA0000000123"
+      }
+    ]
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/account/LA0000000123"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let merchants: Data<SbpMerchantPageData> = serde_json::from_str(merchants).unwrap();
+    let accounts: Data<SbpAccountPageData> = serde_json::from_str(accounts).unwrap();
+
+    assert_eq!(
+        merchants.data.merchant_list[0].capabilities,
+        Some(SbpMerchantCapabilities::StaticAndDynamic)
+    );
+    assert_eq!(
+        accounts.data.account_list[0].status,
+        SbpEntityStatus::Suspended
+    );
+}
+
+#[test]
+fn deserialize_legal_entity() {
+    let json = r#"
+{
+  "Data": {
+    "legalId": "LA0000000123",
+    "status": "Active",
+    "customerCode": "300000092",
+    "name": "ООО Альтер",
+    "inn": "6600000000",
+    "kpp": "668501001"
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/legal-entity/LA0000000123"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let parsed: Data<SbpLegalEntity> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(parsed.data.legal_id, "LA0000000123");
+    assert_eq!(parsed.data.status, SbpEntityStatus::Active);
+}
+
+#[test]
+fn serialize_cashbox_activation_and_refund() {
+    let activation = CashboxQrActivationPayload::new(Money::rub(dec!(250)))
+        .payment_purpose("Кофе")
+        .ttl(5);
+    let refund = SbpRefundPayload::new(
+        "044525104",
+        "40702810840020002503",
+        Money::rub(dec!(250)),
+        "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "B4191103528459050000058B93BF33D6",
+    );
+    let activation_json = serde_json::to_value(&activation).unwrap();
+    let refund_json = serde_json::to_value(&refund).unwrap();
+
+    assert!(activation.validate().is_ok());
+    assert!(refund.validate().is_ok());
+    assert_eq!(activation_json["amount"], 25_000);
+    assert_eq!(activation_json["currency"], "RUB");
+    assert_eq!(activation_json["paymentPurpose"], "Кофе");
+    assert_eq!(
+        refund_json["refTransactionId"],
+        "B4191103528459050000058B93BF33D6"
+    );
+}
+
+#[test]
+fn deserialize_cashbox_payment_and_refund() {
+    let payment = r#"
+{
+  "Data": {
+    "paramsId": "AP1000670LSS7DN18SJQDNP4B05KLJL2",
+    "status": "Accepted",
+    "message": "Платёж завершён",
+    "trxId": "B4191103528459050000058B93BF33D6",
+    "amount": 25000
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/cashbox-qr-code/AS1000670LSS7DN18SJQDNP4B05KLJL2/payment-status"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+    let refund = r#"
+{
+  "Data": {
+    "requestId": "RF1000670LSS7DN18SJQDNP4B05KLJL2",
+    "status": "InProgress"
+  },
+  "Links": {
+    "self": "https://enter.tochka.com/uapi/sbp/v1.0/refund"
+  },
+  "Meta": {
+    "totalPages": 1
+  }
+}
+    "#;
+
+    let payment: Data<CashboxQrPayment> = serde_json::from_str(payment).unwrap();
+    let refund: Data<SbpRefund> = serde_json::from_str(refund).unwrap();
+
+    assert_eq!(payment.data.status, SbpPaymentStatus::Accepted);
+    assert_eq!(payment.data.amount, Some(Money::rub(dec!(250))));
+    assert_eq!(refund.data.status, SbpPaymentStatus::InProgress);
+}
@@ -0,0 +1,54 @@
+use tochka_sdk::Statement;
+
+fn statement() -> Statement {
+    serde_json::from_str(include_str!("fixtures/statement.json")).unwrap()
+}
+
+#[test]
+fn export_1c_matches_golden_file() {
+    let expected = include_bytes!("fixtures/statement.1c.txt");
+
+    assert_eq!(statement().to_1c_bytes(), expected);
+}
+
+#[test]
+fn export_1c_is_windows_1251_with_crlf() {
+    let bytes = statement().to_1c_bytes();
+    let (text, _, had_errors) = encoding_rs::WINDOWS_1251.decode(&bytes);
+
+    assert!(!had_errors);
+    assert_eq!(text, statement().to_1c_exchange());
+    assert!(text.starts_with("1CClientBankExchange\r\nВерсияФормата=1.03\r\n"));
+    assert!(text.ends_with("КонецДокумента\r\nКонецФайла\r\n"));
+    assert!(!text.replace("\r\n", "").contains('\n'));
+}
+
+#[test]
+fn export_1c_writes_tax_fields_only_for_budget_payments() {
+    let text = statement().to_1c_exchange();
+    let documents: Vec<&str> = text.split("СекцияДокумент=").skip(1).collect();
+
+    assert_eq!(documents.len(), 2);
+    assert!(!documents[0].contains("СтатусСоставителя"));
+    assert!(
+        documents[1].contains("СтатусСоставителя=01\r\nПоказательКБК=18201061201010000510\r\n")
+    );
+}
+
+#[test]
+fn export_csv_matches_golden_file() {
+    let expected = include_str!("fixtures/statement.csv");
+
+    assert_eq!(statement().to_csv(), expected);
+}
+
+#[test]
+fn export_without_transactions() {
+    let mut statement = statement();
+    statement.transaction = None;
+
+    let text = statement.to_1c_exchange();
+    assert!(text.contains("ВсегоПоступило=0.00\r\nВсегоСписано=0.00\r\n"));
+    assert!(!text.contains("СекцияДокумент"));
+    assert_eq!(statement.to_csv().lines().count(), 1);
+}
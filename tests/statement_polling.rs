@@ -0,0 +1,151 @@
+mod common;
+
+use axum::{Json, Router, extract::Path, routing::get, routing::post};
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use tochka_sdk::{Client, Error, PollOptions, StatementStatus};
+
+static POLLS: AtomicUsize = AtomicUsize::new(0);
+static BUSY_POLLS: AtomicUsize = AtomicUsize::new(0);
+
+fn statement(account_id: &str, status: &str) -> Json<Value> {
+    Json(json!({
+        "Data": {
+            "Statement": [{
+                "accountId": account_id,
+                "statementId": format!("st-{account_id}"),
+                "status": status,
+                "startDateTime": "2024-01-01",
+                "endDateTime": "2024-01-31",
+                "creationDateTime": "2024-02-01T06:06:06.364+00:00",
+                "startDateBalance": 100.0,
+                "endDateBalance": 150.0,
+                "Transaction": []
+            }]
+        },
+        "Links": { "self": "http://localhost" },
+        "Meta": { "totalPages": 1 }
+    }))
+}
+
+async fn init(Json(body): Json<Value>) -> Json<Value> {
+    match body["Data"]["accountId"].as_str().unwrap() {
+        "empty" => Json(json!({
+            "Data": { "Statement": [] },
+            "Links": { "self": "http://localhost" },
+            "Meta": { "totalPages": 1 }
+        })),
+        account_id => statement(account_id, "Created"),
+    }
+}
+
+async fn poll(Path((account_id, _)): Path<(String, String)>) -> Json<Value> {
+    let status = match account_id.as_str() {
+        "ready" if POLLS.fetch_add(1, Ordering::SeqCst) >= 1 => "Ready",
+        "busy" => {
+            BUSY_POLLS.fetch_add(1, Ordering::SeqCst);
+            "Processing"
+        }
+        "failed" => "Error",
+        _ => "Processing",
+    };
+    statement(&account_id, status)
+}
+
+async fn client() -> Client {
+    let app = Router::new()
+        .route("/open-banking/v1.0/statements", post(init))
+        .route(
+            "/open-banking/v1.0/accounts/{account}/statements/{statement}",
+            get(poll),
+        );
+    common::serve(app).await.0
+}
+
+fn january() -> std::ops::RangeInclusive<NaiveDate> {
+    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+}
+
+fn fast() -> PollOptions {
+    PollOptions::default()
+        .interval(PollOptions::MIN_INTERVAL)
+        .max_interval(Duration::from_millis(20))
+        .timeout(Duration::from_millis(200))
+}
+
+#[tokio::test]
+async fn waits_until_statement_is_ready() {
+    let client = client().await;
+
+    let statement = client
+        .wait_for_statement("ready", january(), fast())
+        .await
+        .unwrap();
+
+    assert_eq!(statement.status, StatementStatus::Ready);
+    assert_eq!(statement.transaction.map(|t| t.len()), Some(0));
+    assert_eq!(POLLS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn failed_statement_is_a_typed_error() {
+    let client = client().await;
+
+    let err = client
+        .wait_for_statement("failed", january(), fast())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::StatementFailed { statement_id } if statement_id == "st-failed"));
+}
+
+#[tokio::test]
+async fn gives_up_after_timeout() {
+    let client = client().await;
+
+    let err = client
+        .wait_for_statement("slow", january(), fast())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, Error::StatementTimeout { waited, .. } if waited <= Duration::from_millis(200))
+    );
+}
+
+#[tokio::test]
+async fn zero_interval_does_not_busy_poll() {
+    let client = client().await;
+    let options = PollOptions {
+        interval: Duration::ZERO,
+        max_interval: Duration::ZERO,
+        timeout: Duration::from_millis(100),
+    };
+
+    let err = client
+        .wait_for_statement("busy", january(), options)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::StatementTimeout { .. }));
+    let max_polls = (options.timeout.as_millis() / PollOptions::MIN_INTERVAL.as_millis()) as usize;
+    assert!(BUSY_POLLS.load(Ordering::SeqCst) <= max_polls);
+}
+
+#[tokio::test]
+async fn empty_statement_list_keeps_raw_body() {
+    let client = client().await;
+
+    let err = client
+        .wait_for_statement("empty", january(), fast())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, Error::MissingData { path, raw } if path == "Data.Statement" && raw.contains("\"Statement\":[]"))
+    );
+}
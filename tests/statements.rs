@@ -1,7 +1,8 @@
 use chrono::{DateTime, NaiveDate};
 use codes_iso_4217::CurrencyCode;
+use rust_decimal_macros::dec;
 use tochka_sdk::{
-    AccountIdentification, Data, FinancialInstitutionIdentification, PaginatedResponse,
+    AccountIdentification, Data, FinancialInstitutionIdentification, Money, PaginatedResponse,
     StatementPageData, StatementStatus, TransactionStatus,
 };
 
@@ -47,7 +48,7 @@ fn deserialize_statements_list_example() {
         statement.start_date_time,
         NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()
     );
-    assert_eq!(statement.start_date_balance, Some(1234.5));
+    assert_eq!(statement.start_date_balance, Some(Money::rub(dec!(1234.5))));
     assert_eq!(parsed.meta.total_pages, 1);
 }
 
@@ -131,8 +132,8 @@ fn deserialize_statement_with_transactions_example() {
 
     assert_eq!(tx.transaction_id.as_deref(), Some("23489"));
     assert!(matches!(tx.status, TransactionStatus::Booked));
-    assert_eq!(tx.subfields.amount.amount, 1000.0);
-    assert_eq!(tx.subfields.amount.currency, CurrencyCode::RUB);
+    assert_eq!(tx.subfields.amount.amount, Money::rub(dec!(1000)));
+    assert_eq!(tx.subfields.amount.currency(), CurrencyCode::RUB);
     assert_eq!(
         tx.subfields.debtor_account.scheme_name,
         AccountIdentification::RUCBRPAN
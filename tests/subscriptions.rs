@@ -0,0 +1,180 @@
+use rust_decimal_macros::dec;
+use serde_json::{Value, json};
+use tochka_sdk::{
+    ChargeSubscriptionPayload, CreateSubscriptionPayload, Data, Error, MOCK_CUSTOMER_CODE,
+    MockServer, Money, SubscriptionListQuery, SubscriptionPageData, SubscriptionPeriod,
+    SubscriptionSchedule, SubscriptionStatus,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+const OPERATION_ID: &str = "beeac93c-ad4b-4ab1-9d3c-4fd5ec3a2e2a";
+
+fn subscription(status: &str) -> Value {
+    json!({
+        "customerCode": "300000092",
+        "amount": 1234.0,
+        "purpose": "Подписка на сервис",
+        "status": status,
+        "operationId": OPERATION_ID,
+        "paymentLink": "https://merch.tochka.com/order/?uuid=beeac93c-ad4b-4ab1-9d3c-4fd5ec3a2e2a",
+        "consumerId": "fedac807-078d-45ac-a43b-5c01c57edbf8",
+        "recurring": false,
+        "Options": { "trancheCount": 12, "period": "Month", "daysInPeriod": 5 }
+    })
+}
+
+fn wrap(data: Value) -> Value {
+    json!({
+        "Data": data,
+        "Links": { "self": "http://localhost" },
+        "Meta": { "totalPages": 1 }
+    })
+}
+
+#[test]
+fn deserialize_subscription_list_example() {
+    let parsed: Data<SubscriptionPageData> =
+        serde_json::from_value(wrap(json!({ "Subscription": [subscription("Active")] }))).unwrap();
+
+    let subscription = &parsed.data.subscription[0];
+    assert_eq!(subscription.status, SubscriptionStatus::Active);
+    assert_eq!(subscription.amount, Money::rub(dec!(1234)));
+    assert_eq!(
+        subscription.options,
+        Some(
+            SubscriptionSchedule::every(SubscriptionPeriod::Month)
+                .tranche_count(12)
+                .days_in_period(5)
+        )
+    );
+}
+
+#[test]
+fn payload_validation() {
+    let payload = CreateSubscriptionPayload::new(Money::rub(dec!(0)), None, "Подписка")
+        .schedule(SubscriptionSchedule::every(SubscriptionPeriod::Week).tranche_count(0));
+
+    let errors = payload.validate().unwrap_err();
+    let fields = errors.errors();
+    assert!(fields.contains_key("amount"));
+    assert!(fields.contains_key("options"));
+}
+
+const SUBSCRIPTIONS_PATH: &str = "/acquiring/v1.0/subscriptions";
+
+#[tokio::test]
+async fn create_list_and_cancel() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let payload = CreateSubscriptionPayload::new(
+        Money::rub(dec!(1234)),
+        Some(MOCK_CUSTOMER_CODE.into()),
+        "Подписка на сервис",
+    )
+    .schedule(SubscriptionSchedule::every(SubscriptionPeriod::Month).tranche_count(12));
+    let created = client.create_subscription(payload).await.unwrap().data;
+    assert_eq!(created.status, SubscriptionStatus::Created);
+
+    let list = client
+        .subscription_list(SubscriptionListQuery::new(Some(MOCK_CUSTOMER_CODE.into())))
+        .await
+        .unwrap();
+    assert_eq!(list.data.subscription, vec![created.clone()]);
+
+    let operation_id = created.operation_id.to_string();
+    let cancelled = client
+        .set_subscription_status(&operation_id, SubscriptionStatus::Cancelled)
+        .await
+        .unwrap();
+    assert!(cancelled.data.result);
+    let status = client.get_subscription_status(&operation_id).await.unwrap();
+    assert_eq!(status.data.status, SubscriptionStatus::Cancelled);
+
+    let requests = server.requests();
+    let create = &requests[0];
+    assert_eq!(create.path, SUBSCRIPTIONS_PATH);
+    assert_eq!(create.idempotency_key, None);
+    let body = create.body.as_ref().unwrap();
+    assert_eq!(body["Data"]["recurring"], json!(false));
+    assert_eq!(body["Data"]["Options"]["period"], json!("Month"));
+    assert_eq!(body["Data"]["Options"]["trancheCount"], json!(12));
+    let status_request = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.path.ends_with("/status"))
+        .unwrap();
+    assert_eq!(
+        status_request.body,
+        Some(json!({ "Data": { "status": "Cancelled" } }))
+    );
+}
+
+#[tokio::test]
+async fn get_subscription_returns_the_single_item() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+    let consumer_id = Uuid::parse_str("fedac807-078d-45ac-a43b-5c01c57edbf8").unwrap();
+    let created = client
+        .create_subscription(
+            CreateSubscriptionPayload::new(
+                Money::rub(dec!(1234)),
+                Some(MOCK_CUSTOMER_CODE.into()),
+                "Подписка на сервис",
+            )
+            .consumer_id(consumer_id),
+        )
+        .await
+        .unwrap()
+        .data;
+
+    let subscription = client
+        .get_subscription(&created.operation_id.to_string())
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(subscription.operation_id, created.operation_id);
+    assert_eq!(subscription.consumer_id, Some(consumer_id));
+
+    let err = client.get_subscription("unknown").await.unwrap_err();
+    assert!(matches!(err, Error::MissingData { path, .. } if path == "Data.Subscription"));
+}
+
+#[tokio::test]
+async fn charge_saved_card_on_demand() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+    let created = client
+        .create_subscription(
+            CreateSubscriptionPayload::new(
+                Money::rub(dec!(99.90)),
+                Some(MOCK_CUSTOMER_CODE.into()),
+                "Подписка по запросу",
+            )
+            .on_demand(),
+        )
+        .await
+        .unwrap()
+        .data;
+    let operation_id = created.operation_id.to_string();
+
+    let charged = client
+        .charge_subscription_with_key(
+            &operation_id,
+            ChargeSubscriptionPayload {
+                amount: Money::rub(dec!(99.90)),
+            },
+            "charge-2025-05",
+        )
+        .await
+        .unwrap();
+
+    assert!(charged.data.result);
+    let charge = server.requests().pop().unwrap();
+    assert_eq!(
+        charge.path,
+        format!("{SUBSCRIPTIONS_PATH}/{operation_id}/charge")
+    );
+    assert_eq!(charge.body, Some(json!({ "Data": { "amount": 99.9 } })));
+    assert_eq!(charge.idempotency_key.as_deref(), Some("charge-2025-05"));
+}
@@ -0,0 +1,255 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use chrono::NaiveDate;
+use futures_util::TryStreamExt;
+use rust_decimal_macros::dec;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tochka_sdk::{
+    CreatePaymentPayload, Error, MOCK_CUSTOMER_CODE, MockServer, Money, PageOptions,
+    PaymentListQuery, PaymentMode, PaymentPath, PaymentStatus, PollOptions, RefundPayload,
+    Statement, StatementStatus, Webhook, WebhookEvent, WebhookType, sign_mock_webhook,
+    webhook_router,
+};
+
+const ACCOUNT: &str = "40817810802000000008/044525104";
+
+fn period() -> std::ops::RangeInclusive<NaiveDate> {
+    NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2019, 1, 31).unwrap()
+}
+
+fn payment(amount: Money) -> CreatePaymentPayload {
+    CreatePaymentPayload::new(amount, Some(MOCK_CUSTOMER_CODE.into()), "Заказ №1")
+        .payment_modes([PaymentMode::Card])
+}
+
+#[tokio::test]
+async fn serves_accounts_and_balances() {
+    let server = MockServer::start().await;
+    server.add_account(ACCOUNT, Money::rub(dec!(2134.50)));
+    let client = server.client().await.unwrap();
+
+    let accounts = client.get_accounts_list().await.unwrap().data.account;
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].account_id, ACCOUNT);
+    assert_eq!(
+        client.resolve_business_customer_code().await.unwrap(),
+        MOCK_CUSTOMER_CODE
+    );
+
+    let balance = client.get_balance_info(ACCOUNT).await.unwrap().data;
+    assert_eq!(balance.amount.amount, Money::rub(dec!(2134.50)));
+
+    server.set_balance(ACCOUNT, Money::rub(dec!(10)));
+    let balances = client.get_balances_list().await.unwrap().data.balance;
+    assert_eq!(balances[0].amount.amount, Money::rub(dec!(10)));
+}
+
+#[tokio::test]
+async fn unknown_account_is_not_found() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let err = client.get_account_into("missing").await.unwrap_err();
+    assert!(matches!(err, Error::NotFound(_)), "{err:?}");
+    assert!(err.api_error().unwrap().has_error_code("Error.NotFound"));
+}
+
+#[tokio::test]
+async fn statement_becomes_ready_after_polls() {
+    let server = MockServer::start().await;
+    server.add_account(ACCOUNT, Money::rub(dec!(2134.50)));
+    let fixture: Statement = serde_json::from_str(include_str!("fixtures/statement.json")).unwrap();
+    for transaction in fixture.transaction.unwrap() {
+        server.add_transaction(ACCOUNT, transaction);
+    }
+    server.statement_polls(2);
+    let client = server.client().await.unwrap();
+
+    let options = PollOptions::default().interval(Duration::from_millis(10));
+    let statement = client
+        .wait_for_statement(ACCOUNT, period(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(statement.status, StatementStatus::Ready);
+    assert_eq!(statement.transaction.unwrap().len(), 2);
+    assert_eq!(
+        statement.start_date_balance,
+        Some(Money::rub(dec!(1234.50)))
+    );
+    assert_eq!(statement.end_date_balance, Some(Money::rub(dec!(2134.50))));
+}
+
+#[tokio::test]
+async fn payment_lifecycle() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let operation = client
+        .create_payment_operation(payment(Money::rub(dec!(100))), PaymentPath::Standard)
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(operation.status, PaymentStatus::Created);
+    assert!(operation.payment_link.starts_with(server.url()));
+
+    server
+        .complete_payment(operation.operation_id, PaymentStatus::Authorized)
+        .await
+        .unwrap();
+    let operation_id = operation.operation_id.to_string();
+    assert!(
+        client
+            .capture_payment(&operation_id)
+            .await
+            .unwrap()
+            .data
+            .result
+    );
+
+    let refund = client
+        .refund_payment_operation(
+            &operation_id,
+            RefundPayload {
+                amount: Money::rub(dec!(40)),
+            },
+        )
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(refund.amount, Money::rub(dec!(40)));
+
+    let info = client.payment_operation_info(&operation_id).await.unwrap();
+    assert_eq!(
+        info.data.operation[0].status,
+        PaymentStatus::RefundedPartially
+    );
+
+    let err = client
+        .refund_payment_operation(
+            &operation_id,
+            RefundPayload {
+                amount: Money::rub(dec!(60.01)),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Api(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn payment_list_is_paginated() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+    for rubles in 1..=5 {
+        client
+            .create_payment_operation(payment(Money::rub(rubles.into())), PaymentPath::Standard)
+            .await
+            .unwrap();
+    }
+
+    let operations: Vec<_> = client
+        .payment_operations_stream(
+            PaymentListQuery::new(Some(MOCK_CUSTOMER_CODE.into())),
+            PageOptions::default().per_page(2),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(operations.len(), 5);
+    assert_eq!(operations[4].amount, Money::rub(dec!(5)));
+    assert_eq!(server.payments().len(), 5);
+}
+
+async fn serve_receiver(server: &MockServer, received: Arc<Mutex<Vec<WebhookEvent>>>) -> String {
+    let client = server.client().await.unwrap();
+    let router = webhook_router(client, move |event: WebhookEvent| {
+        let received = received.clone();
+        async move {
+            received.lock().unwrap().push(event);
+            Ok::<_, String>(())
+        }
+    });
+    let addr = common::listen(router).await;
+    format!("http://{addr}/")
+}
+
+#[tokio::test]
+async fn delivers_signed_webhooks() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let url = serve_receiver(&server, received.clone()).await;
+
+    client
+        .create_webhook(Webhook {
+            webhooks_list: vec![
+                WebhookType::IncomingPayment,
+                WebhookType::AcquiringInternetPayment,
+            ],
+            url,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        client
+            .get_webhooks()
+            .await
+            .unwrap()
+            .data
+            .webhooks_list
+            .len(),
+        2
+    );
+
+    let sent = client
+        .send_webhook(WebhookType::IncomingPayment)
+        .await
+        .unwrap();
+    assert!(sent.data.result);
+
+    let operation = client
+        .create_payment_operation(payment(Money::rub(dec!(250))), PaymentPath::Standard)
+        .await
+        .unwrap()
+        .data;
+    let status = server
+        .complete_payment(operation.operation_id, PaymentStatus::Approved)
+        .await
+        .unwrap();
+    assert_eq!(status.map(|s| s.as_u16()), Some(200));
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].webhook_type(), WebhookType::IncomingPayment);
+    match &received[1] {
+        WebhookEvent::AcquiringInternetPayment(claims) => {
+            assert_eq!(claims.operation_id, operation.operation_id);
+            assert_eq!(claims.amount, Money::rub(dec!(250)));
+            assert_eq!(claims.status, PaymentStatus::Approved);
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn client_accepts_mock_signed_tokens() {
+    let server = MockServer::start().await;
+    let client = server.client().await.unwrap();
+
+    let token = sign_mock_webhook(&serde_json::json!({
+        "operationId": "A1",
+        "qrcId": "AS1",
+        "amount": "1.50",
+        "merchantId": "MA1",
+        "webhookType": "incomingSbpPayment",
+        "customerCode": MOCK_CUSTOMER_CODE
+    }));
+    let event = client.decode_token::<WebhookEvent>(&token).unwrap().claims;
+
+    assert_eq!(event.webhook_type(), WebhookType::IncomingSbpPayment);
+}
@@ -0,0 +1,127 @@
+use chrono::NaiveDate;
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use tochka_sdk::{
+    Client, CreatePaymentPayload, Error, MOCK_CUSTOMER_CODE, MockServer, Money, PaymentPath,
+    PaymentStatus, RefundPayload, RetryPolicy, StatementPayload,
+};
+use uuid::Uuid;
+
+async fn setup(server: &MockServer, skip_validation: bool) -> Client {
+    server
+        .client_builder()
+        .retry_policy(RetryPolicy::none())
+        .skip_validation(skip_validation)
+        .build()
+        .await
+        .unwrap()
+}
+
+/// Оплаченная операция, по которой можно делать возвраты.
+async fn approved_payment(server: &MockServer) -> Uuid {
+    let client = server.client().await.unwrap();
+    let operation = client
+        .create_payment_operation(
+            CreatePaymentPayload::new(
+                Money::rub(dec!(100)),
+                Some(MOCK_CUSTOMER_CODE.into()),
+                "Заказ",
+            ),
+            PaymentPath::Standard,
+        )
+        .await
+        .unwrap()
+        .data;
+    server
+        .complete_payment(operation.operation_id, PaymentStatus::Approved)
+        .await
+        .unwrap();
+    operation.operation_id
+}
+
+/// Сколько возвратов дошло до сервера.
+fn refund_requests(server: &MockServer) -> usize {
+    server
+        .requests()
+        .iter()
+        .filter(|request| request.path.ends_with("/refund"))
+        .count()
+}
+
+fn zero_refund() -> RefundPayload {
+    RefundPayload {
+        amount: Money::rub(dec!(0)),
+    }
+}
+
+#[tokio::test]
+async fn invalid_payload_is_rejected_before_sending() {
+    let server = MockServer::start().await;
+    let client = setup(&server, false).await;
+    let operation_id = approved_payment(&server).await;
+
+    let err = client
+        .refund_payment_operation(operation_id.to_string(), zero_refund())
+        .await
+        .unwrap_err();
+
+    let Error::Validation(errors) = err else {
+        panic!("expected validation error, got {err:?}");
+    };
+    assert_eq!(errors.field_errors()["amount"][0].code, "amount_positive");
+    assert_eq!(refund_requests(&server), 0);
+}
+
+#[tokio::test]
+async fn field_errors_cover_every_invalid_field() {
+    let server = MockServer::start().await;
+    let client = setup(&server, false).await;
+    let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+    let statement = client
+        .init_statement(StatementPayload {
+            account_id: "4".repeat(41),
+            start_date_time: date,
+            end_date_time: date,
+        })
+        .await;
+    assert!(matches!(
+        statement,
+        Err(Error::Validation(errors)) if errors.field_errors().contains_key("account_id")
+    ));
+
+    let payment = client
+        .create_payment_operation(
+            CreatePaymentPayload::new(
+                Money::rub(dec!(-1)),
+                Some(MOCK_CUSTOMER_CODE.into()),
+                "x".repeat(141),
+            ),
+            PaymentPath::Standard,
+        )
+        .await;
+    let Err(Error::Validation(errors)) = payment else {
+        panic!("expected validation error");
+    };
+    let fields = errors.field_errors();
+    assert!(fields.contains_key("amount"));
+    assert!(fields.contains_key("purpose"));
+}
+
+#[tokio::test]
+async fn skip_validation_sends_payload_as_is() {
+    let server = MockServer::start().await;
+    let client = setup(&server, true).await;
+    let operation_id = approved_payment(&server).await;
+
+    let err = client
+        .refund_payment_operation(operation_id.to_string(), zero_refund())
+        .await
+        .unwrap_err();
+
+    let Error::Api(api) = err else {
+        panic!("expected the API to reject the refund, got {err:?}");
+    };
+    assert_eq!(api.status, StatusCode::BAD_REQUEST);
+    assert_eq!(refund_requests(&server), 1);
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "axum")]
+
+mod common;
+
+use async_trait::async_trait;
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tochka_sdk::{
+    Client, Environment, Error, Jwk, JwkCache, JwkSet, JwkSource, WebhookEvent, WebhookType,
+    testing::{mock_jwk, sign_mock_webhook as sign},
+    webhook_router,
+};
+
+/// Отдаёт только старый ключ; ключ, которым подписаны вебхуки, появляется после обновления.
+#[derive(Debug, Clone, Default)]
+struct RotatingSource {
+    fetches: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl JwkSource for RotatingSource {
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        let key = match self.fetches.fetch_add(1, Ordering::SeqCst) {
+            0 => Jwk {
+                kty: "RSA".into(),
+                n: "stub".into(),
+                e: "AQAB".into(),
+                kid: Some("retired".into()),
+                alg: Some("RS256".into()),
+            },
+            _ => mock_jwk(),
+        };
+        Ok(key.into())
+    }
+}
+
+async fn serve(received: Arc<Mutex<Vec<WebhookEvent>>>) -> String {
+    serve_with(
+        received,
+        JwkCache::new(tochka_sdk::StaticJwkSource(mock_jwk().into())),
+    )
+    .await
+}
+
+async fn serve_with(received: Arc<Mutex<Vec<WebhookEvent>>>, cache: JwkCache) -> String {
+    let client = Client::builder()
+        .environment(Environment::Sandbox)
+        .jwk_cache(cache)
+        .build()
+        .await
+        .unwrap();
+    let router = webhook_router(client, move |event: WebhookEvent| {
+        let received = received.clone();
+        async move {
+            if event.customer_code() == "000000000" {
+                return Err("unknown customer");
+            }
+            received.lock().unwrap().push(event);
+            Ok(())
+        }
+    });
+    let app = axum::Router::new().nest("/tochka", router);
+
+    let addr = common::listen(app).await;
+
+    format!("http://{addr}/tochka")
+}
+
+fn sbp_claims(customer_code: &str) -> serde_json::Value {
+    serde_json::json!({
+        "operationId": "A41580807342410000000011400201",
+        "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "amount": "250.00",
+        "merchantId": "MA0000000552",
+        "webhookType": "incomingSbpPayment",
+        "customerCode": customer_code
+    })
+}
+
+async fn post(url: &str, body: String) -> u16 {
+    reqwest::Client::new()
+        .post(url)
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+#[tokio::test]
+async fn receiver_dispatches_valid_webhook() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let url = serve(received.clone()).await;
+
+    let status = post(&url, sign(&sbp_claims("300000092"))).await;
+
+    assert_eq!(status, 200);
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].webhook_type(), WebhookType::IncomingSbpPayment);
+}
+
+#[tokio::test]
+async fn receiver_maps_failures_to_status_codes() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let url = serve(received.clone()).await;
+
+    let mut forged = sign(&sbp_claims("300000092"));
+    forged.truncate(forged.rfind('.').unwrap() + 1);
+    forged.push_str("AAAA");
+    let unknown_type = sign(&serde_json::json!({ "webhookType": "somethingElse" }));
+
+    assert_eq!(post(&url, forged).await, 401);
+    assert_eq!(post(&url, "not a jwt".into()).await, 400);
+    assert_eq!(post(&url, unknown_type).await, 400);
+    assert_eq!(post(&url, sign(&sbp_claims("000000000"))).await, 500);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn receiver_refreshes_keys_after_rotation() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let source = RotatingSource::default();
+    let cache = JwkCache::new(source.clone()).refresh_interval(Duration::ZERO);
+    let url = serve_with(received.clone(), cache).await;
+
+    let status = post(&url, sign(&sbp_claims("300000092"))).await;
+
+    assert_eq!(status, 200);
+    assert_eq!(source.fetches.load(Ordering::SeqCst), 2);
+    assert_eq!(received.lock().unwrap().len(), 1);
+}
@@ -1,4 +1,18 @@
-use tochka_sdk::{Data, Webhook, WebhookType};
+use codes_iso_4217::CurrencyCode;
+use rust_decimal_macros::dec;
+use tochka_sdk::testing::{mock_jwk, sign_mock_webhook as sign};
+use tochka_sdk::{
+    Client, Data, Environment, Error, Money, PaymentSide, Webhook, WebhookEvent, WebhookType,
+};
+
+async fn client() -> Client {
+    Client::builder()
+        .environment(Environment::Sandbox)
+        .jwk(mock_jwk())
+        .build()
+        .await
+        .unwrap()
+}
 
 #[test]
 fn deserialize_webhook_response_example() {
@@ -31,3 +45,112 @@ fn deserialize_webhook_response_example() {
         ]
     ));
 }
+
+#[tokio::test]
+async fn decode_incoming_payment_webhook() {
+    let token = sign(&serde_json::json!({
+        "SidePayer": {
+            "bankCode": "044525104",
+            "bankName": "ООО Банк Точка",
+            "bankCorrAccount": "30101810745374525104",
+            "account": "40702810840020002503",
+            "name": "ООО Альтер",
+            "amount": "1500.00",
+            "currency": "RUB",
+            "inn": "6600000000",
+            "kpp": "668501001"
+        },
+        "SideRecipient": {
+            "bankCode": "044525104",
+            "account": "40702810140020001007",
+            "name": "ИП Иванов",
+            "amount": "1500.00",
+            "inn": "660000000000"
+        },
+        "purpose": "Оплата по счету № 7",
+        "documentNumber": "12",
+        "paymentId": "cbs-tb-92-466347393",
+        "date": "2024-05-01",
+        "webhookType": "incomingPayment",
+        "customerCode": "300000092"
+    }));
+
+    let event = client().await.decode_webhook(&token).await.unwrap();
+
+    assert_eq!(event.webhook_type(), WebhookType::IncomingPayment);
+    assert_eq!(event.customer_code(), "300000092");
+    let WebhookEvent::IncomingPayment(claims) = event else {
+        panic!("unexpected variant");
+    };
+    assert_eq!(claims.side_payer.name, "ООО Альтер");
+    assert_eq!(claims.side_payer.amount, Money::rub(dec!(1500)));
+    assert_eq!(claims.side_recipient.kpp, None);
+    assert_eq!(claims.side_recipient.currency(), CurrencyCode::RUB);
+}
+
+#[test]
+fn payment_side_amount_takes_the_side_currency() {
+    let side: PaymentSide = serde_json::from_value(serde_json::json!({
+        "bankCode": "044525104",
+        "account": "40702840840020002503",
+        "name": "ООО Альтер",
+        "amount": "250.10",
+        "currency": "USD"
+    }))
+    .unwrap();
+
+    assert_eq!(side.amount, Money::new(dec!(250.10), CurrencyCode::USD));
+    let json = serde_json::to_value(&side).unwrap();
+    assert_eq!(json["currency"], serde_json::json!("USD"));
+}
+
+#[tokio::test]
+async fn decode_sbp_webhooks() {
+    let client = client().await;
+    let sbp = sign(&serde_json::json!({
+        "operationId": "A41580807342410000000011400201",
+        "qrcId": "AS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "amount": "250.00",
+        "payerMobileNumber": "+79990000000",
+        "payerName": "Иван Иванович И.",
+        "brandName": "Кофейня",
+        "merchantId": "MA0000000552",
+        "purpose": "Кофе",
+        "webhookType": "incomingSbpPayment",
+        "customerCode": "300000092"
+    }));
+    let b2b = sign(&serde_json::json!({
+        "operationId": "B41580807342410000000011400201",
+        "qrcId": "BS1000670LSS7DN18SJQDNP4B05KLJL2",
+        "amount": "10000.00",
+        "merchantId": "MA0000000552",
+        "webhookType": "incomingSbpB2BPayment",
+        "customerCode": "300000092"
+    }));
+
+    assert!(matches!(
+        client.decode_webhook(&sbp).await.unwrap(),
+        WebhookEvent::IncomingSbpPayment(claims) if claims.brand_name.as_deref() == Some("Кофейня")
+    ));
+    assert!(matches!(
+        client.decode_webhook(&b2b).await.unwrap(),
+        WebhookEvent::IncomingSbpB2BPayment(claims) if claims.side_payer.is_none()
+    ));
+}
+
+#[tokio::test]
+async fn decode_webhook_rejects_bad_payloads() {
+    let client = client().await;
+    let unknown = sign(&serde_json::json!({ "webhookType": "somethingElse" }));
+    let mut tampered = sign(&serde_json::json!({ "webhookType": "incomingSbpPayment" }));
+    tampered.push('x');
+
+    assert!(matches!(
+        client.decode_webhook(&unknown).await,
+        Err(Error::InvalidToken(_))
+    ));
+    assert!(matches!(
+        client.decode_webhook(&tampered).await,
+        Err(Error::InvalidToken(_))
+    ));
+}